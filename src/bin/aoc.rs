@@ -0,0 +1,251 @@
+use aoc2023::days::puzzle;
+use aoc2023::input::{Source, INPUT_DIR};
+use aoc2023::Error;
+use aoc2023::solver::Part;
+use aoc2023::timing::{to_csv, to_json, to_table, DayTiming};
+use aoc2023::verify::{check, parse_answers, Outcome};
+use std::env;
+use std::fs;
+use std::panic;
+use std::process::exit;
+
+const USAGE: &str = "\
+Usage:
+  aoc run <day> [--input <path>] [--name <name>] [--input-dir <dir>] [--part 1|2]
+                                     Solve one day from a file, from <dir>/dayNN/<name>.txt, or
+                                     from stdin if neither is given. Solves both parts unless
+                                     --part picks one, e.g. for an example that only has one
+  aoc run all [--input-dir <dir>]    Solve every day from <dir>/dayNN/real.txt (default: inputs)
+  aoc verify [--answers <path>] [--input-dir <dir>]
+                                     Check every day against the answers file (default: answers.toml)
+  aoc time <day> [--input <path>] [--name <name>] [--input-dir <dir>] [--part 1|2] [--reps <n>] [--format table|json|csv]
+  aoc time all [--input-dir <dir>] [--reps <n>] [--format table|json|csv]
+                                     Time parsing and each part over n runs (default: 10)";
+
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    eprintln!("{}", USAGE);
+    exit(2);
+}
+
+fn read_input(source: &Source) -> String {
+    source.read_to_string().unwrap_or_else(|e| usage_error(&e.to_string()))
+}
+
+fn input_error(source: &str, err: &Error) -> ! {
+    eprintln!("Error in {}: {}", source, err);
+    exit(1);
+}
+
+fn parse_day(s: &str) -> u32 {
+    match s.parse::<u32>() {
+        Ok(day) if puzzle(day).is_some() => day,
+        _ => usage_error(&format!("No such day: {}", s)),
+    }
+}
+
+fn parse_parts(part: Option<&str>) -> Vec<Part> {
+    match part {
+        None => Part::BOTH.to_vec(),
+        Some("1") => vec![Part::One],
+        Some("2") => vec![Part::Two],
+        Some(part) => usage_error(&format!("No such part: {}", part)),
+    }
+}
+
+fn run_day(day: u32, source: &Source, parts: &[Part]) {
+    let input = read_input(source);
+    let answers = (puzzle(day).unwrap().solve)(&input, parts)
+        .unwrap_or_else(|e| input_error(&source.to_string(), &e));
+    for answer in answers {
+        println!("{}", answer);
+    }
+}
+
+fn run_all(input_dir: &str) {
+    for day in 1..=25 {
+        let source = Source::named(input_dir, day, "real");
+        println!("Day {:02}", day);
+        match source.read_to_string() {
+            Ok(input) => match (puzzle(day).unwrap().solve)(&input, &Part::BOTH) {
+                Ok(answers) => {
+                    for (part, answer) in Part::BOTH.iter().zip(answers) {
+                        println!("  {}: {}", part, answer);
+                    }
+                },
+                Err(e) => println!("  Error in {}: {}", source, e),
+            },
+            Err(_) => println!("  No input at {}", source),
+        }
+    }
+}
+
+fn verify(answers_path: &str, input_dir: &str) {
+    let text = fs::read_to_string(answers_path)
+        .unwrap_or_else(|e| usage_error(&format!("Failed to read {}: {}", answers_path, e)));
+    let entries = parse_answers(&text)
+        .unwrap_or_else(|e| usage_error(&format!("Bad answers file {}: {}", answers_path, e)));
+
+    let mut passed = 0;
+    let mut failed = 0;
+    for expected in &entries {
+        let name = format!("day{:02} {}", expected.day, expected.input);
+        let solve = match puzzle(expected.day) {
+            Some(puzzle) => puzzle.solve,
+            None => {
+                println!("{}: FAIL (no such day)", name);
+                failed += 1;
+                continue;
+            }
+        };
+        let source = Source::named(input_dir, expected.day, &expected.input);
+        let input = match source.read_to_string() {
+            Ok(input) => input,
+            Err(_) => {
+                println!("{}: FAIL (no input at {})", name, source);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let parts: Vec<Part> = expected.parts().iter().map(|(part, _)| *part).collect();
+        match panic::catch_unwind(|| solve(&input, &parts)) {
+            Ok(Ok(answers)) => {
+                for ((part, expected_answer), answer) in expected.parts().iter().zip(answers) {
+                    let outcome = check(expected_answer, &answer);
+                    if outcome == Outcome::Pass {
+                        passed += 1;
+                    } else {
+                        failed += 1;
+                    }
+                    println!("{} {}: {}", name, part, outcome);
+                }
+            },
+            Ok(Err(e)) => {
+                println!("{}: FAIL ({})", name, e);
+                failed += parts.len();
+            },
+            Err(_) => {
+                println!("{}: FAIL (panicked)", name);
+                failed += parts.len();
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+    if failed > 0 {
+        exit(1);
+    }
+}
+
+fn time_day(day: u32, input: &str, parts: &[Part], reps: usize) -> Result<DayTiming, Error> {
+    let time = puzzle(day).unwrap().time;
+    let runs = (0..reps).map(|_| time(input, parts)).collect::<Result<Vec<_>, _>>()?;
+    Ok(DayTiming::new(day, &runs))
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+fn parse_format(format: Option<&str>) -> Format {
+    match format {
+        None | Some("table") => Format::Table,
+        Some("json") => Format::Json,
+        Some("csv") => Format::Csv,
+        Some(format) => usage_error(&format!("Unknown format: {}", format)),
+    }
+}
+
+fn print_timings(rows: &[DayTiming], format: Format) {
+    match format {
+        Format::Table => print!("{}", to_table(rows)),
+        Format::Json => print!("{}", to_json(rows)),
+        Format::Csv => print!("{}", to_csv(rows)),
+    }
+}
+
+fn time_all(input_dir: &str, reps: usize, format: Format) {
+    let mut rows = Vec::new();
+    for day in 1..=25 {
+        let source = Source::named(input_dir, day, "real");
+        match source.read_to_string() {
+            Ok(input) => match time_day(day, &input, &Part::BOTH, reps) {
+                Ok(row) => rows.push(row),
+                Err(e) => eprintln!("Skipping day {:02}: error in {}: {}", day, source, e),
+            },
+            Err(_) => eprintln!("Skipping day {:02}: no input at {}", day, source),
+        }
+    }
+    print_timings(&rows, format);
+}
+
+fn parse_reps(reps: Option<&str>) -> usize {
+    match reps.map(|s| s.parse::<usize>()) {
+        None => 10,
+        Some(Ok(n)) if n > 0 => n,
+        _ => usage_error("The number of repetitions must be a positive integer"),
+    }
+}
+
+// Pick out the values of options like "--input <path>", rejecting anything unexpected.
+fn options<'a>(args: &'a [String], names: &[&str]) -> Vec<Option<&'a str>> {
+    let mut ret = vec![None; names.len()];
+    for pair in args.chunks(2) {
+        match (names.iter().position(|n| *n == pair[0]), pair.get(1)) {
+            (Some(i), Some(value)) => ret[i] = Some(value.as_str()),
+            _ => usage_error(&format!("Unexpected arguments: {}", args.join(" "))),
+        }
+    }
+    ret
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => {
+            match args.get(1).map(|s| s.as_str()) {
+                Some("all") => {
+                    let opts = options(&args[2..], &["--input-dir"]);
+                    run_all(opts[0].unwrap_or(INPUT_DIR));
+                },
+                Some(day) => {
+                    let opts = options(&args[2..], &["--input", "--name", "--input-dir", "--part"]);
+                    let day = parse_day(day);
+                    let parts = parse_parts(opts[3]);
+                    run_day(day, &Source::resolve(opts[0], opts[2].unwrap_or(INPUT_DIR), day, opts[1]), &parts);
+                },
+                None => usage_error("Missing day"),
+            }
+        },
+        Some("time") => {
+            match args.get(1).map(|s| s.as_str()) {
+                Some("all") => {
+                    let opts = options(&args[2..], &["--input-dir", "--reps", "--format"]);
+                    time_all(opts[0].unwrap_or(INPUT_DIR), parse_reps(opts[1]), parse_format(opts[2]));
+                },
+                Some(day) => {
+                    let opts = options(&args[2..], &["--input", "--name", "--input-dir", "--part", "--reps", "--format"]);
+                    let day = parse_day(day);
+                    let (parts, reps, format) = (parse_parts(opts[3]), parse_reps(opts[4]), parse_format(opts[5]));
+                    let source = Source::resolve(opts[0], opts[2].unwrap_or(INPUT_DIR), day, opts[1]);
+                    let input = read_input(&source);
+                    let row = time_day(day, &input, &parts, reps)
+                        .unwrap_or_else(|e| input_error(&source.to_string(), &e));
+                    print_timings(&[row], format);
+                },
+                None => usage_error("Missing day"),
+            }
+        },
+        Some("verify") => {
+            let opts = options(&args[1..], &["--answers", "--input-dir"]);
+            verify(opts[0].unwrap_or("answers.toml"), opts[1].unwrap_or(INPUT_DIR));
+        },
+        Some("help") | Some("--help") | Some("-h") => println!("{}", USAGE),
+        _ => usage_error("Missing or unknown command"),
+    }
+}
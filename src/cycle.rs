@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// The shape of the sequence x, f(x), f(f(x)), ... once it starts repeating: the first `start`
+// states come before the cycle, which then repeats every `len` states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    // The index of the earlier state that state n is equal to, which is n itself before the
+    // cycle starts.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start { n } else { self.start + (n - self.start) % self.len }
+    }
+}
+
+// Floyd's tortoise and hare. Needs only two states at a time, but calls f about three times
+// for every state up to the end of the first lap of the cycle.
+pub fn floyd<T, F>(initial: T, mut f: F) -> Cycle
+    where T: Clone + PartialEq, F: FnMut(&T) -> T
+{
+    let mut x1 = f(&initial);
+    let mut x2 = f(&x1);
+    while x1 != x2 {
+        x1 = f(&x1);
+        x2 = f(&x2);
+        x2 = f(&x2);
+    }
+
+    let mut start = 0;
+    x1 = initial;
+    while x1 != x2 {
+        x1 = f(&x1);
+        x2 = f(&x2);
+        start += 1;
+    }
+
+    let mut len = 1;
+    x1 = f(&x1);
+    while x1 != x2 {
+        x1 = f(&x1);
+        len += 1;
+    }
+
+    Cycle{ start, len }
+}
+
+// Brent's algorithm. Also needs only two states at a time, with fewer calls to f than Floyd.
+pub fn brent<T, F>(initial: T, mut f: F) -> Cycle
+    where T: Clone + PartialEq, F: FnMut(&T) -> T
+{
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = f(&initial);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = f(&hare);
+        len += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..len {
+        hare = f(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+
+    Cycle{ start, len }
+}
+
+// Remember every state until one repeats. Calls f exactly once per distinct state, and returns
+// those states so that any of them can be looked up without calling f again.
+pub fn memoized<T, F>(initial: T, mut f: F) -> (Cycle, Vec<T>)
+    where T: Clone + Eq + Hash, F: FnMut(&T) -> T
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+    loop {
+        let last = states.last().unwrap();
+        if let Some(&start) = seen.get(last) {
+            let len = states.len() - 1 - start;
+            states.pop();
+            return (Cycle{ start, len }, states);
+        }
+        seen.insert(last.clone(), states.len() - 1);
+        let next = f(last);
+        states.push(next);
+    }
+}
+
+// The state after applying f n times, skipping over whole laps once a cycle is found.
+pub fn nth_state<T, F>(initial: T, mut f: F, n: usize) -> T
+    where T: Clone + Eq + Hash, F: FnMut(&T) -> T
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+    while states.len() <= n {
+        let last = states.last().unwrap();
+        if let Some(&start) = seen.get(last) {
+            let cycle = Cycle{ start, len: states.len() - 1 - start };
+            return states.swap_remove(cycle.reduce(n));
+        }
+        seen.insert(last.clone(), states.len() - 1);
+        let next = f(last);
+        states.push(next);
+    }
+    states.swap_remove(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 2, 3, 4, 5, ...
+    fn f(&x: &i64) -> i64 {
+        if x == 5 { 2 } else { x + 1 }
+    }
+
+    #[test]
+    fn test_detection() {
+        let expected = Cycle{ start: 2, len: 4 };
+        assert_eq!(floyd(0, f), expected);
+        assert_eq!(brent(0, f), expected);
+        assert_eq!(memoized(0, f), (expected, vec![0, 1, 2, 3, 4, 5]));
+        assert_eq!(floyd(3, f), Cycle{ start: 0, len: 4 });
+        assert_eq!(brent(7, |_| 7), Cycle{ start: 0, len: 1 });
+    }
+
+    #[test]
+    fn test_nth_state() {
+        assert_eq!(nth_state(0, f, 0), 0);
+        assert_eq!(nth_state(0, f, 5), 5);
+        assert_eq!(nth_state(0, f, 6), 2);
+        assert_eq!(nth_state(0, f, 1_000_000_000), 2 + (1_000_000_000 - 2) % 4);
+
+        let mut calls = 0;
+        nth_state(0, |x| { calls += 1; f(x) }, 1_000_000_000);
+        assert_eq!(calls, 6);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+
+fn find_substrings<'a>(haystack: &'a str, needles: &Vec<&str>) -> Vec<&'a str> {
+    let mut ret = Vec::<&'a str>::new();
+    for (i, _) in haystack.char_indices() {
+        for needle in needles {
+            if haystack[i..].starts_with(needle) {
+                ret.push(&haystack[i..i + needle.len()]);
+                break;
+            }
+        }
+    }
+    ret
+}
+
+fn parse_digit(s: &str) -> i64 {
+    match s {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        _ => s.parse::<i64>().unwrap()
+    }
+}
+
+fn calibration_sum(lines: &[String], pattern: &str) -> i64 {
+    let needles: Vec<&str> = pattern.split('|').collect();
+    let mut sum = 0i64;
+    for line in lines {
+        let nums = find_substrings(line, &needles);
+        if !nums.is_empty() {
+            sum += parse_digit(nums[0]) * 10 + parse_digit(nums[nums.len() - 1]);
+        }
+    }
+    sum
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        calibration_sum(input, "1|2|3|4|5|6|7|8|9").into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        calibration_sum(input, "1|2|3|4|5|6|7|8|9|one|two|three|four|five|six|seven|eight|nine").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(super::Day01, "01", part1: ["example1"], part2: ["example2"]);
+
+    #[test]
+    fn test_find_substrings() {
+        assert_eq!(find_substrings("é1two€", &vec!["1", "two"]), vec!["1", "two"]);
+    }
+}
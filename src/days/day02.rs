@@ -0,0 +1,78 @@
+use regex::Regex;
+use std::collections::HashMap;
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+use crate::utils::{captures, parse_lines, parse_num};
+
+fn parse_set(set_line: &str) -> Result<HashMap<String, i64>> {
+    let clause_regex: Regex = Regex::new("(\\d+) (blue|red|green)").unwrap();
+    let mut ret = HashMap::new();
+    for cap in clause_regex.captures_iter(set_line) {
+        let (_, [count, color]) = cap.extract();
+        ret.insert(String::from(color), parse_num(count)?);
+    };
+    Ok(ret)
+}
+
+fn parse_line(line: &str) -> Result<(i64, Vec<HashMap<String, i64>>)> {
+    let line_regex: Regex = Regex::new("Game (\\d+): (.*)").unwrap();
+
+    let (_, [game_id, sets]) = captures(&line_regex, line)?.extract();
+    let clauses: Vec<&str> = sets.split(";").collect();
+
+    Ok((parse_num(game_id)?,
+        clauses.iter().map(|clause| parse_set(clause)).collect::<Result<_>>()?))
+}
+
+const RED_LIMIT: i64 = 12;
+const GREEN_LIMIT: i64 = 13;
+const BLUE_LIMIT: i64 = 14;
+
+fn is_possible(sets: &[HashMap<String, i64>]) -> bool {
+    for set in sets {
+        if *set.get("red").unwrap_or(&0) > RED_LIMIT
+            || *set.get("green").unwrap_or(&0) > GREEN_LIMIT
+            || *set.get("blue").unwrap_or(&0) > BLUE_LIMIT {
+            return false
+        }
+    }
+    true
+}
+
+fn minimal_counts(sets: &[HashMap<String, i64>]) -> (i64, i64, i64) {
+    let reds = *sets.iter().map(|s| s.get("red").unwrap_or(&0)).max().unwrap_or(&0);
+    let greens = *sets.iter().map(|s| s.get("green").unwrap_or(&0)).max().unwrap_or(&0);
+    let blues = *sets.iter().map(|s| s.get("blue").unwrap_or(&0)).max().unwrap_or(&0);
+    (reds, greens, blues)
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input = Vec<(i64, Vec<HashMap<String, i64>>)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter()
+            .filter(|(_, sets)| is_possible(sets))
+            .map(|(game_id, _)| game_id)
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.iter()
+            .map(|(_, sets)| minimal_counts(sets))
+            .map(|(reds, greens, blues)| reds * greens * blues)
+            .sum::<i64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day02, "02", part1: ["example1"], part2: ["example1"]);
+}
@@ -1,5 +1,6 @@
-use aoc2023::grid::{Point, Grid, GridBuilder};
-use std::io;
+use crate::grid::{Point, Grid};
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
 
 struct Tag {
     start: Point,
@@ -26,11 +27,11 @@ fn find_tags(grid: &Grid<char>) -> Vec<Tag> {
         }
     }
 
-    return ret;
+    ret
 }
 
 fn is_symbol(ch: char) -> bool {
-    return !ch.is_ascii_digit() && ch != '.';
+    !ch.is_ascii_digit() && ch != '.'
 }
 
 fn neighbours(tag: &Tag, grid: &Grid<char>) -> Vec<Point> {
@@ -49,7 +50,7 @@ fn neighbours(tag: &Tag, grid: &Grid<char>) -> Vec<Point> {
     if grid.contains(tag.start.row, tag.start.col + tag.len) {
         ret.push(Point{row: tag.start.row, col: tag.start.col + tag.len});
     }
-    return ret;
+    ret
 }
 
 fn is_part_number(grid: &Grid<char>, tag: &Tag) -> bool {
@@ -58,7 +59,7 @@ fn is_part_number(grid: &Grid<char>, tag: &Tag) -> bool {
             return true;
         }
     }
-    return false;
+    false
 }
 
 fn to_number(grid: &Grid<char>, tag: &Tag) -> i64 {
@@ -66,18 +67,11 @@ fn to_number(grid: &Grid<char>, tag: &Tag) -> i64 {
     for c in tag.start.col..tag.start.col+tag.len {
         ret = ret * 10 + (*grid.get(tag.start.row, c) as i64 - '0' as i64)
     }
-    return ret;
+    ret
 }
 
 fn make_gear_grid(grid: &Grid<char>) -> Grid<Vec<i64>> {
-    let mut gear_grid_builder = GridBuilder::<Vec<i64>>::new();
-    for _row in 0..grid.height() {
-        for _col in 0..grid.width() {
-            gear_grid_builder.add(vec![]);
-        }
-        gear_grid_builder.eol();
-    }
-    return gear_grid_builder.finish();
+    Grid::from_dim(grid.height(), grid.width(), vec![])
 }
 
 fn mark_gears(grid: &Grid<char>, gear_grid: &mut Grid<Vec<i64>>, tags: &[Tag]) {
@@ -91,34 +85,35 @@ fn mark_gears(grid: &Grid<char>, gear_grid: &mut Grid<Vec<i64>>, tags: &[Tag]) {
 }
 
 fn sum_gear_ratios(gear_grid: &Grid<Vec<i64>>) -> i64 {
-    let mut sum = 0;
-    for row in 0..gear_grid.height() {
-        for col in 0..gear_grid.width() {
-            let entry = gear_grid.get(row, col);
-            if entry.len() == 2 {
-                sum += entry[0] * entry[1];
-            }
-        }
-    }
-    return sum;
+    gear_grid.iter()
+        .filter(|entry| entry.len() == 2)
+        .map(|entry| entry[0] * entry[1])
+        .sum()
 }
 
-fn main() {
-    let mut grid_builder = GridBuilder::<char>::new();
-    for line in io::stdin().lines().map(|l| l.unwrap()) {
-        for ch in line.chars() {
-            grid_builder.add(ch);
-        }
-        grid_builder.eol();
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
     }
-    let grid = grid_builder.finish();
 
-    let tags = find_tags(&grid);
+    fn part1(grid: &Self::Input) -> Answer {
+        let tags = find_tags(grid);
+        tags.iter().filter(|t| is_part_number(grid, t)).map(|t| to_number(grid, t)).sum::<i64>().into()
+    }
 
-    let parts_sum: i64 = tags.iter().filter(|t| is_part_number(&grid, *t)).map(|t| to_number(&grid, t)).sum();
-    println!("{}", parts_sum);
+    fn part2(grid: &Self::Input) -> Answer {
+        let tags = find_tags(grid);
+        let mut gear_grid = make_gear_grid(grid);
+        mark_gears(grid, &mut gear_grid, &tags);
+        sum_gear_ratios(&gear_grid).into()
+    }
+}
 
-    let mut gear_grid = make_gear_grid(&grid);
-    mark_gears(&grid, &mut gear_grid, &tags);
-    println!("{}", sum_gear_ratios(&gear_grid));
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day03, "03", part1: ["example1"], part2: ["example1"]);
 }
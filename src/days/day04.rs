@@ -0,0 +1,68 @@
+use regex::Regex;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+use crate::utils::{captures, parse_lines, parse_num};
+
+pub struct Card {
+    winning: Vec<i64>,
+    ticket: Vec<i64>,
+}
+
+fn card_matches(card: &Card) -> usize {
+    let winning: HashSet<i64> = HashSet::from_iter(card.winning.iter().cloned());
+    let ticket: HashSet<i64> = HashSet::from_iter(card.ticket.iter().cloned());
+    winning.intersection(&ticket).count()
+}
+
+fn score(card: &Card) -> i64 {
+    let matches = card_matches(card);
+    if matches == 0 {
+        0
+    } else {
+        i64::pow(2, (matches - 1) as u32)
+    }
+}
+
+fn parse_card(line: &str) -> Result<Card> {
+    let line_regex = Regex::new("Card\\s+\\d+: ([0-9 ]+) \\| ([0-9 ]+)").unwrap();
+    let (_, [winning_str, ticket_str]) = captures(&line_regex, line)?.extract();
+    let mut winning: Vec<i64> = winning_str.split_ascii_whitespace().map(parse_num).collect::<Result<_>>()?;
+    let mut ticket: Vec<i64> = ticket_str.split_ascii_whitespace().map(parse_num).collect::<Result<_>>()?;
+    winning.sort();
+    ticket.sort();
+    Ok(Card{ winning, ticket })
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_card)
+    }
+
+    fn part1(cards: &Self::Input) -> Answer {
+        cards.iter().map(score).sum::<i64>().into()
+    }
+
+    fn part2(cards: &Self::Input) -> Answer {
+        let mut card_counts: Vec<usize> = vec![1; cards.len()];
+        for i in 0..cards.len() {
+            let matches = card_matches(&cards[i]);
+            for j in i + 1 ..= i + matches {
+                if j < cards.len() {
+                    card_counts[j] += card_counts[i];
+                }
+            }
+        }
+        card_counts.iter().sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day04, "04", part1: ["example1"], part2: ["example1"]);
+}
@@ -0,0 +1,105 @@
+use crate::interval::{Interval, IntervalSet, OffsetMap};
+use crate::utils::grab_numbers;
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+
+pub type Step = OffsetMap<i64>;
+
+fn parse_input(lines: &[String]) -> Result<(Vec<i64>, Vec<Step>)> {
+    let seeds = grab_numbers(lines.first().ok_or_else(|| Error::new("Missing seeds"))?).map_err(|e| e.at_line(1))?;
+    if seeds.is_empty() {
+        return Err(Error::new("Missing seeds").at_line(1));
+    }
+    // Part 2 reads the seeds as pairs of start and length.
+    if seeds.len() % 2 != 0 {
+        return Err(Error::new(format!("Expected an even number of seeds, got {}", seeds.len())).at_line(1));
+    }
+
+    let mut mappings: Vec<Step> = Vec::new();
+    mappings.push(Step::new());
+    let mut i = 3usize;
+    while i < lines.len() {
+        let nums = grab_numbers(&lines[i]).map_err(|e| e.at_line(i + 1))?;
+        if nums.is_empty() {
+            i += 2;
+            mappings.push(Step::new());
+        } else if let [dst_start, src_start, len] = nums[..] {
+            i += 1;
+            mappings.last_mut().unwrap().insert(Interval::new(src_start, src_start + len), dst_start - src_start);
+        } else {
+            return Err(Error::new("Expected a mapping of three numbers").at_line(i + 1));
+        }
+    }
+
+    Ok((seeds, mappings))
+}
+
+fn seed_to_location(seed: i64, steps: &[Step]) -> i64 {
+    steps.iter().fold(seed, |value, s| s.map(value))
+}
+
+fn seed_ranges_to_locations(seeds: &IntervalSet<i64>, steps: &[Step]) -> IntervalSet<i64> {
+    steps.iter().fold(seeds.clone(), |ranges, s| s.map_set(&ranges))
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = (Vec<i64>, Vec<Step>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<String> = input
+            .lines()
+            .map(String::from)
+            .collect();
+        parse_input(&lines)
+    }
+
+    fn part1((seeds, steps): &Self::Input) -> Answer {
+        seeds.iter().map(|s| seed_to_location(*s, steps)).min().unwrap().into()
+    }
+
+    fn part2((seeds, steps): &Self::Input) -> Answer {
+        let seed_ranges = seeds.chunks(2)
+            .map(|pair| Interval::new(pair[0], pair[0] + pair[1]))
+            .collect();
+        seed_ranges_to_locations(&seed_ranges, steps).min().unwrap().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day05, "05", part1: ["example1"], part2: ["example1"]);
+
+    use super::*;
+
+    #[test]
+    fn test_parse_seeds() {
+        let err = Day05::parse("seeds: 1 2 3\n").err().unwrap();
+        assert_eq!(err.to_string(), "line 1: Expected an even number of seeds, got 3");
+        assert_eq!(Day05::parse("").err().unwrap().to_string(), "Missing seeds");
+    }
+
+    #[test]
+    fn test_seed_ranges_to_locations() {
+        fn step(mappings: &[(i64, i64, i64)]) -> Step {
+            let mut step = Step::new();
+            for &(dst_start, src_start, len) in mappings {
+                step.insert(Interval::new(src_start, src_start + len), dst_start - src_start);
+            }
+            step
+        }
+        let seeds = IntervalSet::from(Interval::new(10, 20));
+        let ranges = |s: &[(i64, i64)]| s.iter().map(|&(b, e)| Interval::new(b, e)).collect::<IntervalSet<i64>>();
+        assert_eq!(seed_ranges_to_locations(&seeds, &[]), seeds);
+        assert_eq!(seed_ranges_to_locations(&seeds, &[step(&[(0, 3, 4)])]), seeds);
+        assert_eq!(seed_ranges_to_locations(&seeds, &[step(&[(1, 8, 4)])]), ranges(&[(3, 5), (12, 20)]));
+        assert_eq!(seed_ranges_to_locations(&seeds, &[step(&[(1, 12, 4)])]), ranges(&[(1, 5), (10, 12), (16, 20)]));
+        assert_eq!(seed_ranges_to_locations(&seeds, &[step(&[(1, 18, 4)])]), ranges(&[(1, 3), (10, 18)]));
+        assert_eq!(seed_ranges_to_locations(&seeds, &[step(&[(1, 20, 4)])]), seeds);
+        assert_eq!(seed_ranges_to_locations(&seeds, &[step(&[(111, 11, 2), (115, 15, 2), (119, 19, 3)])]),
+                   ranges(&[(10, 11), (13, 15), (17, 19), (111, 113), (115, 117), (119, 120)]));
+        assert_eq!(seed_ranges_to_locations(&seeds, &[step(&[(0, 10, 5)]), step(&[(100, 0, 3)])]),
+                   ranges(&[(3, 5), (15, 20), (100, 103)]));
+    }
+}
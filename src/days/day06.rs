@@ -1,6 +1,7 @@
 use std::cmp::max;
-use aoc2023::utils::grab_numbers;
-use std::io;
+use crate::utils::grab_numbers;
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
 
 fn isqrt_floor(x: i64) -> i64 {
     if x <= 0 {
@@ -18,7 +19,7 @@ fn isqrt_floor(x: i64) -> i64 {
         s /= 2;
     }
 
-    return r;
+    r
 }
 
 fn race(duration: i64, held_time: i64) -> i64 {
@@ -40,7 +41,7 @@ fn count_wins(duration: i64, record: i64) -> i64 {
     while race(duration, high) <= record {
         high -= 1;
     }
-    return high - low + 1;
+    high - low + 1
 }
 
 fn all_digits(s: &str) -> i64 {
@@ -53,27 +54,48 @@ fn all_digits(s: &str) -> i64 {
     ret
 }
 
-fn main() {
-    let lines: Vec<String> = io::stdin()
-        .lines()
-        .map(|l| l.unwrap())
-        .collect();
-    let times = grab_numbers(&lines[0]);
-    let distances = grab_numbers(&lines[1]);
-
-    let wins_prod: i64 = times.iter().zip(distances)
-        .map(|(duration, record)| count_wins(*duration, record))
-        .product();
-    println!("{}", wins_prod);
-
-    let long_time = all_digits(&lines[0]);
-    let long_record = all_digits(&lines[1]);
-    println!("{}", count_wins(long_time, long_record));
+pub struct Races {
+    times: Vec<i64>,
+    distances: Vec<i64>,
+    long_time: i64,
+    long_record: i64,
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<&str> = input.lines().collect();
+        if lines.len() != 2 {
+            return Err(Error::new(format!("Expected a line of times and a line of distances, got {} lines", lines.len())));
+        }
+        let times = grab_numbers(lines[0]).map_err(|e| e.at_line(1))?;
+        let distances = grab_numbers(lines[1]).map_err(|e| e.at_line(2))?;
+        if times.len() != distances.len() {
+            return Err(Error::new("Expected as many distances as times").at_line(2));
+        }
+        Ok(Races { times, distances, long_time: all_digits(lines[0]), long_record: all_digits(lines[1]) })
+    }
+
+    fn part1(races: &Self::Input) -> Answer {
+        races.times.iter().zip(&races.distances)
+            .map(|(duration, record)| count_wins(*duration, *record))
+            .product::<i64>()
+            .into()
+    }
+
+    fn part2(races: &Self::Input) -> Answer {
+        count_wins(races.long_time, races.long_record).into()
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
+    crate::example_tests!(super::Day06, "06", part1: ["example1"], part2: ["example1"]);
+
     use super::*;
 
     #[test]
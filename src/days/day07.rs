@@ -1,8 +1,11 @@
 use std::cmp::{Ordering,Ord};
 use regex::Regex;
-use std::io;
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+use crate::utils::{captures, parse_lines, parse_num};
 
-struct Hand {
+#[derive(Clone)]
+pub struct Hand {
     cards: String,
     bid: i64,
 }
@@ -37,18 +40,13 @@ impl Counter {
     }
 }
 
-fn parse_input() -> Vec<Hand> {
-    let pattern = Regex::new("([AKQJT2-9]{5}) ([0-9]+)").unwrap();
-    let lines: Vec<String> = io::stdin()
-        .lines()
-        .map(|l| l.unwrap())
-        .collect();
+fn parse_input(input: &str) -> Result<Vec<Hand>> {
+    let pattern = Regex::new("^([AKQJT2-9]{5}) ([0-9]+)$").unwrap();
 
-    lines.into_iter().map(|line| {
-        let c = pattern.captures(&line).unwrap();
-        Hand { cards: String::from(c.get(1).unwrap().as_str()), bid: c.get(2).unwrap().as_str().parse().unwrap() }
+    parse_lines(input, |line| {
+        let c = captures(&pattern, line)?;
+        Ok(Hand { cards: String::from(&c[1]), bid: parse_num(&c[2])? })
     })
-        .collect()
 }
 
 fn count_hand(hand: &Hand) -> (Count, i64) {
@@ -101,14 +99,14 @@ fn rank_hand(hand: &Hand) -> HandType {
         HandType::FourOfAKind
     } else if max_n + n_jokers == 3 {
         let (second_n, _) = counts[1];
-        return if second_n == 2 {
+        if second_n == 2 {
             HandType::FullHouse
         } else {
             HandType::ThreeOfAKind
         }
     } else if max_n + n_jokers == 2 {
         let (second_n, _) = counts[1];
-        return if second_n == 2 {
+        if second_n == 2 {
             HandType::TwoPair
         } else {
             HandType::OnePair
@@ -137,16 +135,33 @@ fn winnings(hands: &[Hand]) -> i64 {
         .sum()
 }
 
-fn main() {
-    let mut hands = parse_input();
+pub struct Day07;
 
-    hands.sort_by(|h1, h2| compare_hands(h1, h2));
-    println!("{}", winnings(&hands));
+impl Solver for Day07 {
+    type Input = Vec<Hand>;
 
-    for h in &mut hands {
-        h.cards = h.cards.replace("J","*");
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
     }
 
-    hands.sort_by(|h1, h2| compare_hands(h1, h2));
-    println!("{}", winnings(&hands));
+    fn part1(input: &Self::Input) -> Answer {
+        let mut hands = input.clone();
+        hands.sort_by(compare_hands);
+        winnings(&hands).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut hands = input.clone();
+        for h in &mut hands {
+            h.cards = h.cards.replace("J","*");
+        }
+
+        hands.sort_by(compare_hands);
+        winnings(&hands).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day07, "07", part1: ["example1"], part2: ["example1"]);
 }
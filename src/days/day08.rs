@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use regex::Regex;
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+use crate::utils::captures;
+
+pub type Map = HashMap<String, (String, String)>;
+
+fn read_map(map_lines: &[&str]) -> Result<Map> {
+    let mut ret = HashMap::new();
+    let pattern = Regex::new("([0-9A-Z]{3}) = \\(([0-9A-Z]{3}), ([0-9A-Z]{3})\\)").unwrap();
+    for (i, line) in map_lines.iter().enumerate() {
+        let cap = captures(&pattern, line).map_err(|e| e.at_line(i + 1))?;
+        let (_, [first, left, right]) = cap.extract();
+        ret.insert(String::from(first),(String::from(left), String::from(right)));
+    }
+    Ok(ret)
+}
+
+fn check_targets(map: &Map) -> Result<()> {
+    for (node, (left, right)) in map {
+        for target in [left, right] {
+            if !map.contains_key(target) {
+                return Err(Error::new(format!("Node {} leads to unknown node {}", node, target)));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn follow(instructions: &str, map: &Map, start: &str) -> usize {
+    let mut count = 0;
+    let mut pos = &String::from(start);
+
+    while !pos.ends_with("Z") {
+        let dir: char = instructions.chars().nth(count % instructions.len()).unwrap();
+        if dir == 'L' {
+            pos = &map.get(pos).unwrap().0;
+        } else {
+            pos = &map.get(pos).unwrap().1;
+        }
+        count += 1;
+    }
+    count
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while a > 0 {
+        (a, b) = (b % a, a);
+    }
+    b
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a * b / gcd(a, b)
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = (String, Map);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<&str> = input.lines().collect();
+        if lines.len() < 2 {
+            return Err(Error::new("Expected a line of instructions followed by the map"));
+        }
+        if lines[0].is_empty() {
+            return Err(Error::new("No instructions").at_line(1));
+        }
+        if let Some(pos) = lines[0].find(|c| c != 'L' && c != 'R') {
+            return Err(Error::new("Instructions must be L or R").at_line(1).at_col(pos + 1));
+        }
+
+        let map = read_map(&lines[2..]).map_err(|e| e.offset_lines(2))?;
+        check_targets(&map)?;
+        Ok((String::from(lines[0]), map))
+    }
+
+    fn part1((instructions, map): &Self::Input) -> Answer {
+        // Examples for part 2 don't have to include AAA, so it's only checked here.
+        if !map.contains_key("AAA") {
+            return Answer::from("No node AAA to start from");
+        }
+        follow(instructions, map, "AAA").into()
+    }
+
+    fn part2((instructions, map): &Self::Input) -> Answer {
+        let starts: Vec<String> = map.keys().filter(|&s| s.ends_with("A")).cloned().collect();
+
+        // We can make a simplifying assumption, that each start only leads to one single ending
+        let lengths: Vec<usize> = starts.iter().map(|s| follow(instructions, map, s)).collect();
+
+        match lengths.iter().cloned().reduce(lcm) {
+            Some(steps) => steps.into(),
+            None => Answer::from("No nodes ending in A to start from"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day08, "08", part1: ["example1", "example2"], part2: ["example3"]);
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let err = Day08::parse("\n\nAAA = (ZZZ, ZZZ)").err().unwrap();
+        assert_eq!(err.to_string(), "line 1: No instructions");
+        let err = Day08::parse("L\n\nAAA = (ZZZ, BBB)\nZZZ = (ZZZ, ZZZ)").err().unwrap();
+        assert_eq!(err.to_string(), "Node AAA leads to unknown node BBB");
+
+        let input = Day08::parse("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(Day08::part1(&input), Answer::from("No node AAA to start from"));
+        assert_eq!(Day08::part2(&input), Answer::from("No nodes ending in A to start from"));
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 3), 3);
+        assert_eq!(gcd(3, 3), 3);
+        assert_eq!(gcd(3, 3), 3);
+        assert_eq!(gcd(15, 18), 3);
+        assert_eq!(gcd(77, 19), 1);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::utils::{grab_numbers, parse_lines};
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+
+pub type Sequence = Vec<i64>;
+
+fn parse_input(input: &str) -> Result<Vec<Sequence>> {
+    parse_lines(input, grab_numbers)
+}
+
+fn diff(s: &Sequence) -> Sequence {
+    let mut ret = Sequence::new();
+    for i in 1..s.len() {
+        ret.push(s[i] - s[i-1]);
+    }
+    ret
+}
+
+fn all_zero(s: &Sequence) -> bool {
+    s.iter().all(|n| *n == 0)
+}
+
+fn integrate(diffs: &[Sequence]) -> i64 {
+    let mut ret = 0;
+    for s in diffs {
+        ret += s.last().unwrap();
+    }
+    ret
+}
+
+fn integrate_pre(diffs: &[Sequence]) -> i64 {
+    let mut ret = 0;
+    // next + prev = first ==> next = first - prev
+    for s in diffs.iter().rev() {
+        ret = s.first().unwrap() - ret;
+    }
+    ret
+}
+
+fn predict(s: &Sequence) -> (i64, i64) {
+    let mut stack: Vec<Sequence> = Vec::new();
+    stack.push(s.clone());
+    while !all_zero(stack.last().unwrap()) {
+        stack.push(diff(stack.last().unwrap()))
+    }
+    (integrate_pre(&stack), integrate(&stack))
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = Vec<Sequence>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().map(predict).map(|(_, post)| post).sum::<i64>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.iter().map(predict).map(|(pre, _)| pre).sum::<i64>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day09, "09", part1: ["example1"], part2: ["example1"]);
+}
@@ -0,0 +1,120 @@
+use crate::dir::{step, Dir};
+use crate::geometry::{interior_points, shoelace};
+use crate::grid::{Grid, Point};
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+
+fn enterable_via(pipe: char, dir: Dir) -> bool {
+    match dir {
+        Dir::Up => pipe == '|' || pipe == 'F' || pipe == '7',
+        Dir::Right => pipe == '-' || pipe == 'J' || pipe == '7',
+        Dir::Down => pipe == '|' || pipe == 'J' || pipe == 'L',
+        Dir::Left => pipe == '-' || pipe == 'F' || pipe == 'L',
+    }
+}
+
+fn exit_of(pipe: char, in_dir: Dir) -> Dir {
+    match (pipe, in_dir) {
+        ('|', Dir::Up) => Dir::Up,
+        ('|', Dir::Down) => Dir::Down,
+        ('-', Dir::Left) => Dir::Left,
+        ('-', Dir::Right) => Dir::Right,
+        ('J', Dir::Down) => Dir::Left,
+        ('J', Dir::Right) => Dir::Up,
+        ('L', Dir::Left) => Dir::Up,
+        ('L', Dir::Down) => Dir::Right,
+        ('7', Dir::Right) => Dir::Down,
+        ('7', Dir::Up) => Dir::Left,
+        ('F', Dir::Left) => Dir::Down,
+        ('F', Dir::Up) => Dir::Right,
+        _ => panic!("Mismatch: {} {:?}", pipe, in_dir)
+    }
+}
+
+fn find_start(grid: &Grid<char>) -> Option<Point> {
+    grid.iter_points().find(|(_, &ch)| ch == 'S').map(|(p, _)| p)
+}
+
+fn fix_start_pos(grid: &mut Grid<char>) -> Result<Point> {
+    let start = find_start(grid).ok_or_else(|| Error::new("No starting tile 'S' in the grid"))?;
+
+    let matches = |dir: Dir| {
+        let next = step(start, dir);
+        grid.containsp(&next) && enterable_via(*grid.getp(&next), dir)
+    };
+
+    let tiles = [
+        (Dir::Up, Dir::Down, '|'),
+        (Dir::Up, Dir::Right, 'L'),
+        (Dir::Up, Dir::Left, 'J'),
+        (Dir::Right, Dir::Left, '-'),
+        (Dir::Down, Dir::Right, 'F'),
+        (Dir::Down, Dir::Left, '7'),
+    ];
+
+    for (dir1, dir2, ch) in tiles {
+        if matches(dir1) && matches(dir2) {
+            *grid.mutgetp(&start) = ch;
+            return Ok(start)
+        }
+    }
+    Err(Error::new("Could not find a way to replace starting tile")
+        .at_line(start.row as usize + 1)
+        .at_col(start.col as usize + 1))
+}
+
+fn trace_loop(grid: &Grid<char>, start: &Point) -> Vec<(Point, Dir)> {
+    let mut dir = match *grid.getp(start) {
+        '|' => Dir::Down,
+        'F' => Dir::Down,
+        '7' => Dir::Down,
+        '-' => Dir::Right,
+        'L' => Dir::Right,
+        'J' => Dir::Left,
+        _ => panic!("Start square bad"),
+    };
+
+    let mut path = Vec::new();
+    path.push((*start, dir));
+    let mut next = step(*start, dir);
+
+    while next != *start {
+        let out_dir = exit_of(*grid.getp(&next), dir);
+        path.push((next, out_dir));
+        dir = out_dir;
+        next = step(next, dir);
+    }
+
+    path
+}
+
+fn inner_area(path: &[(Point, Dir)]) -> i64 {
+    let vertices: Vec<Point> = path.iter().map(|&(p, _)| p).collect();
+    interior_points(shoelace(&vertices), path.len() as i64)
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = (Grid<char>, Point);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut grid = input.parse()?;
+        let start = fix_start_pos(&mut grid)?;
+        Ok((grid, start))
+    }
+
+    fn part1((grid, start): &Self::Input) -> Answer {
+        (trace_loop(grid, start).len() / 2).into()
+    }
+
+    fn part2((grid, start): &Self::Input) -> Answer {
+        let path = trace_loop(grid, start);
+        inner_area(&path).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day10, "10", part1: ["example1"], part2: ["example2", "example3"]);
+}
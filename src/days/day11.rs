@@ -0,0 +1,86 @@
+use crate::grid::Point;
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+
+fn parse_input(input: &str) -> Vec<Point> {
+    let mut ret = Vec::new();
+    for (row, line) in input.lines().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            if ch == '#' {
+                ret.push(Point{ row: row as i32, col: col as i32 });
+            }
+        }
+    }
+    ret
+}
+
+fn expand_space(stars: &[Point], amount: i32) -> Vec<Point> {
+    let mut star_list = Vec::from(stars);
+
+    star_list.sort_by_key(|s| s.col);
+    let mut dc = 0;
+    let mut col = star_list[0].col;
+    for s in &mut star_list {
+        if s.col > col + 1 {
+            dc += (s.col - col - 1) * (amount - 1);
+        }
+        col = s.col;
+        s.col += dc;
+    }
+
+    star_list.sort_by_key(|s| s.row);
+    let mut dr = 0;
+    let mut row = star_list[0].row;
+    for s in &mut star_list {
+        if s.row > row + 1 {
+            dr += (s.row - row - 1) * (amount - 1);
+        }
+        row = s.row;
+        s.row += dr;
+    }
+
+    star_list
+}
+
+fn pairwise_distance_sum(stars: &[Point]) -> i64 {
+    let mut sum = 0;
+    for (i, s1) in stars.iter().enumerate() {
+        for s2 in stars[i+1..].iter() {
+            sum += (*s1 - *s2).manhattan() as i64
+        }
+    }
+    sum
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input_stars: &Self::Input) -> Answer {
+        pairwise_distance_sum(&expand_space(input_stars, 2)).into()
+    }
+
+    fn part2(input_stars: &Self::Input) -> Answer {
+        pairwise_distance_sum(&expand_space(input_stars, 1_000_000)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(super::Day11, "11", part1: ["example1"]);
+
+    // Part 2 expands by a million, but the puzzle text gives sums for smaller factors.
+    #[test]
+    fn test_expand_space() {
+        let stars = parse_input(include_str!("../../inputs/day11/example1.txt"));
+        assert_eq!(pairwise_distance_sum(&expand_space(&stars, 10)), 1030);
+        assert_eq!(pairwise_distance_sum(&expand_space(&stars, 100)), 8410);
+    }
+}
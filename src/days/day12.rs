@@ -1,5 +1,7 @@
 use std::cmp::min;
-use aoc2023::utils::{stdio_lines, grab_numbers};
+use crate::utils::{grab_numbers, parse_lines};
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
 use std::collections::HashMap;
 use std::iter::FromIterator;
 
@@ -10,28 +12,29 @@ enum SpringState {
     Unknown,
 }
 
-struct Record {
+pub struct Record {
     springs: Vec<SpringState>,
     groups: Vec<i64>,
 }
 
-fn parse_line(line: &str) -> Record {
-    let parts: Vec<&str> = line.split_ascii_whitespace().into_iter().collect();
-    assert_eq!(parts.len(), 2);
-    let springs = parts[0].chars().map(|ch| {
+fn parse_line(line: &str) -> Result<Record> {
+    let (springs, _) = line.split_once(' ')
+        .ok_or_else(|| Error::new("Expected springs and groups separated by a space"))?;
+    let springs = springs.chars().enumerate().map(|(i, ch)| {
         match ch {
-            '.' => SpringState::Operational,
-            '#' => SpringState::Damaged,
-            '?' => SpringState::Unknown,
-            _ => panic!("invalid char"),
+            '.' => Ok(SpringState::Operational),
+            '#' => Ok(SpringState::Damaged),
+            '?' => Ok(SpringState::Unknown),
+            _ => Err(Error::new(format!("Invalid spring {:?}", ch)).at_col(i + 1)),
         }
-    }).collect();
-    let groups = grab_numbers(parts[1]);
-    Record{ springs, groups }
+    }).collect::<Result<_>>()?;
+    // The springs contain no digits, so the numbers on the line are exactly the groups.
+    let groups = grab_numbers(line)?;
+    Ok(Record{ springs, groups })
 }
 
-fn parse_input() -> Vec<Record> {
-    stdio_lines().iter().map(|line| parse_line(line)).collect()
+fn parse_input(input: &str) -> Result<Vec<Record>> {
+    parse_lines(input, parse_line)
 }
 
 fn can_be_operational(springs: &[SpringState]) -> bool {
@@ -42,24 +45,6 @@ fn can_be_damaged(springs: &[SpringState]) -> bool {
     springs.iter().all(|s| *s != SpringState::Operational)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_can_be_operational() {
-        use SpringState::*;
-        assert!(can_be_operational(&[]));
-        assert!(can_be_operational(&[Operational]));
-        assert!(can_be_operational(&[Unknown]));
-        assert!(can_be_operational(&[Operational, Unknown]));
-        assert!(can_be_operational(&[Unknown, Operational]));
-        assert!(!can_be_operational(&[Damaged]));
-        assert!(!can_be_operational(&[Operational, Damaged]));
-        assert!(!can_be_operational(&[Unknown, Damaged]));
-    }
-}
-
 fn count_options(record: &Record) -> usize {
     let mut table: HashMap<(usize, usize), usize> = HashMap::new();
 
@@ -117,9 +102,40 @@ fn expand_record(record: &Record) -> Record {
     Record { springs, groups }
 }
 
-fn main() {
-    let records = parse_input();
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Vec<Record>;
 
-    println!("{}", records.iter().map(count_options).sum::<usize>());
-    println!("{}", records.iter().map(expand_record).map(|r| count_options(&r)).sum::<usize>());
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(records: &Self::Input) -> Answer {
+        records.iter().map(count_options).sum::<usize>().into()
+    }
+
+    fn part2(records: &Self::Input) -> Answer {
+        records.iter().map(expand_record).map(|r| count_options(&r)).sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day12, "12", part1: ["example1"], part2: ["example1"]);
+
+    use super::*;
+
+    #[test]
+    fn test_can_be_operational() {
+        use SpringState::*;
+        assert!(can_be_operational(&[]));
+        assert!(can_be_operational(&[Operational]));
+        assert!(can_be_operational(&[Unknown]));
+        assert!(can_be_operational(&[Operational, Unknown]));
+        assert!(can_be_operational(&[Unknown, Operational]));
+        assert!(!can_be_operational(&[Damaged]));
+        assert!(!can_be_operational(&[Operational, Damaged]));
+        assert!(!can_be_operational(&[Unknown, Damaged]));
+    }
 }
@@ -0,0 +1,105 @@
+use std::cmp::min;
+use crate::grid::Grid;
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+
+pub type Pattern = Grid<char>;
+
+fn parse_input(input: &str) -> Result<Vec<Pattern>> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut ret = Vec::new();
+
+    // Grid errors are reported relative to the pattern, so track where the current one started.
+    let mut section_start = 0;
+    for section in lines.split(|line| line.is_empty()) {
+        if !section.is_empty() {
+            ret.push(Grid::from_lines(section).map_err(|e| e.offset_lines(section_start))?);
+        }
+        section_start += section.len() + 1;
+    }
+
+    Ok(ret)
+}
+
+fn encode_rows(pattern: &Pattern) -> Vec<i64> {
+    pattern.rows()
+        .map(|row| row.iter().fold(0, |enc, ch| enc * 2 + if *ch == '#' { 1 } else { 0 }))
+        .collect()
+}
+
+fn bit_sum(x: i64) -> usize {
+    let mut ret = 0;
+    for i in 0..64 {
+        if ((1 << i) & x) != 0 {
+            ret += 1
+        }
+    }
+    ret
+}
+
+fn bit_difference(x: i64, y: i64) -> usize {
+    bit_sum(x ^ y)
+}
+
+fn count_smudges(encoded_pattern: &[i64], pos: usize) -> usize {
+    assert!(pos + 1 < encoded_pattern.len());
+    let n = min(pos + 1, encoded_pattern.len() - pos - 1);
+    let mut ret = 0;
+    for delta in 1..=n {
+        ret += bit_difference(encoded_pattern[pos + 1 - delta], encoded_pattern[pos + delta]);
+    }
+    ret
+}
+
+fn find_reflection(encoded_pattern: &[i64], expected_smudges: usize) -> Option<usize> {
+    for i in 1..encoded_pattern.len() {
+        if count_smudges(encoded_pattern, i-1) == expected_smudges {
+            return Some(i-1);
+        }
+    }
+    None
+}
+
+#[derive(PartialEq)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+fn find_reflection_line(pattern: &Pattern, expected_smudges: usize) -> Option<(Axis, usize)> {
+    let row_match = find_reflection(&encode_rows(pattern), expected_smudges);
+    let col_match = find_reflection(&encode_rows(&pattern.transpose()), expected_smudges);
+    row_match.map(|row| (Axis::Horizontal, row))
+        .or_else(|| col_match.map(|col| (Axis::Vertical, col)))
+}
+
+fn summarize(patterns: &[Pattern], expected_smudges: usize) -> usize {
+    patterns.iter()
+        .map(|p| find_reflection_line(p, expected_smudges))
+        .map(|x| x.unwrap())
+        .map(|(axis, n)| (n + 1) * if axis == Axis::Horizontal { 100 } else { 1 })
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        summarize(input, 0).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        summarize(input, 1).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day13, "13", part1: ["example1"], part2: ["example1"]);
+}
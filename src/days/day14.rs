@@ -0,0 +1,67 @@
+use crate::cycle::nth_state;
+use crate::grid::Grid;
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+
+fn copy_fixed(grid: &Grid<char>) -> Grid<char> {
+    let mut ret = grid.clone();
+    for ch in ret.iter_mut().filter(|ch| **ch != '#') {
+        *ch = '.';
+    }
+    ret
+}
+
+fn roll_north(grid: &Grid<char>) -> Grid<char> {
+    let mut new_grid = copy_fixed(grid);
+    for col in 0..grid.width() {
+        let mut rock_count = 0;
+        for row in (-1..grid.height()).rev() {
+            if row == -1 || *grid.get(row, col) == '#' {
+                for r in row+1..row+1+rock_count {
+                    *new_grid.mutget(r, col) = 'O';
+                }
+                rock_count = 0;
+            } else if *grid.get(row, col) == 'O' {
+                rock_count += 1;
+            }
+        }
+    }
+
+    new_grid
+}
+
+// Roll north, west, south and east in turn. Rotating clockwise after each roll brings the next
+// direction to the top, and after four rotations the grid is back in its original orientation.
+fn cycle(grid: &Grid<char>) -> Grid<char> {
+    (0..4).fold(grid.clone(), |g, _| roll_north(&g).rotate_cw())
+}
+
+fn total_load_north(grid: &Grid<char>) -> i64 {
+    grid.iter_points()
+        .filter(|(_, &ch)| ch == 'O')
+        .map(|(p, _)| (grid.height() - p.row) as i64)
+        .sum()
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        total_load_north(&roll_north(grid)).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        total_load_north(&nth_state(input.clone(), cycle, 1_000_000_000)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day14, "14", part1: ["example1"], part2: ["example1"]);
+}
@@ -0,0 +1,132 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::parse_num;
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op {
+    Remove(String),
+    Insert(String, i64),
+}
+
+// A step of the initialization sequence, kept as written for hashing in part 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    text: String,
+    op: Op,
+}
+
+// A step is either "label-" or "label=N".
+fn parse_step(step: &str) -> Result<Step> {
+    let op = if let Some(label) = step.strip_suffix("-") {
+        Op::Remove(String::from(label))
+    } else if let Some((label, lens)) = step.split_once("=") {
+        Op::Insert(String::from(label), parse_num::<u32>(lens)? as i64)
+    } else {
+        return Err(Error::new(format!("Invalid step {:?}", step)));
+    };
+    Ok(Step{ text: String::from(step), op })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step>> {
+    let mut ret = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let mut col = 1;
+        for part in line.split(",") {
+            ret.push(parse_step(part).map_err(|e| e.at_line(i + 1).at_col(col))?);
+            col += part.len() + 1;
+        }
+    }
+    Ok(ret)
+}
+
+fn hash(s: &str) -> i64 {
+    let mut h = 0;
+    for ch in s.chars() {
+        h = ((h + ch as i64) * 17) % 256
+    }
+    h
+}
+
+fn set_lens(boxes: &mut [Vec<(String, i64)>], label: &str, val: i64) {
+    let boxno = hash(label);
+    let lenses = &mut boxes[boxno as usize];
+    for (i, (lbl, _)) in lenses.iter().enumerate() {
+        if lbl == label {
+            lenses[i] = (String::from(label), val);
+            return;
+        }
+    }
+    lenses.push((String::from(label), val));
+}
+
+fn remove_lens(boxes: &mut [Vec<(String, i64)>], label: &str) {
+    let boxno = hash(label);
+    let lenses = &mut boxes[boxno as usize];
+    for (i, (lbl, _)) in lenses.iter().enumerate() {
+        if lbl == label {
+            lenses.remove(i);
+            return;
+        }
+    }
+}
+
+fn execute(boxes: &mut [Vec<(String, i64)>], op: &Op) {
+    match op {
+        Op::Remove(label) => remove_lens(boxes, label),
+        Op::Insert(label, lens) => set_lens(boxes, label, *lens),
+    }
+}
+
+fn focusing_power(boxes: &[(String, i64)], boxno: i64) -> i64 {
+    boxes.iter()
+        .enumerate()
+        .map(|(i, (_, val))| (boxno + 1) * (i as i64 + 1) * val)
+        .sum::<i64>()
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter()
+            .map(|step| hash(&step.text))
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut boxes = Vec::new();
+        boxes.resize(256, Vec::new());
+        for step in input {
+            execute(&mut boxes, &step.op);
+        }
+
+        boxes.iter()
+            .enumerate()
+            .map(|(i, b)| focusing_power(b, i as i64))
+            .sum::<i64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(super::Day15, "15", part1: ["example1"], part2: ["example1"]);
+
+    #[test]
+    fn test_parse_step() {
+        assert_eq!(parse_step("rn=1").unwrap().op, Op::Insert(String::from("rn"), 1));
+        assert_eq!(parse_step("cm-").unwrap().op, Op::Remove(String::from("cm")));
+        assert!(parse_step("a=99999999999999999999").is_err());
+        assert!(parse_step("a=-1").is_err());
+        assert!(parse_step("a").is_err());
+    }
+}
@@ -1,9 +1,11 @@
 use std::collections::HashSet;
-use aoc2023::grid::{Grid, GridBuilder, Point};
-use aoc2023::utils::stdio_lines;
+use crate::dir::{step, Dir};
+use crate::grid::{Grid, Point};
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
 
 #[derive(Eq, PartialEq, Copy, Clone)]
-enum Square {
+pub enum Square {
     Empty,
     MirrorULDR,
     MirrorURDL,
@@ -11,33 +13,16 @@ enum Square {
     SplitterVert,
 }
 
-fn parse_input() -> Grid<Square> {
-    let mut builder = GridBuilder::new();
-
+fn parse_input(input: &str) -> Result<Grid<Square>> {
     use Square::*;
-    for line in stdio_lines() {
-        for ch in line.chars() {
-            match ch {
-                '.' => builder.add(Empty),
-                '/' => builder.add(MirrorURDL),
-                '\\' => builder.add(MirrorULDR),
-                '-' => builder.add(SplitterHoriz),
-                '|' => builder.add(SplitterVert),
-                _ => panic!("Invalid input token"),
-            }
-        }
-        builder.eol();
-    }
-
-    builder.finish()
-}
-
-#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
-enum Dir {
-    Up,
-    Right,
-    Down,
-    Left,
+    Grid::parse(input, |ch| match ch {
+        '.' => Ok(Empty),
+        '/' => Ok(MirrorURDL),
+        '\\' => Ok(MirrorULDR),
+        '-' => Ok(SplitterHoriz),
+        '|' => Ok(SplitterVert),
+        _ => Err(Error::new(format!("Invalid input token {:?}", ch))),
+    })
 }
 
 fn interact(in_dir: Dir, square: Square) -> Vec<Dir> {
@@ -60,16 +45,6 @@ fn interact(in_dir: Dir, square: Square) -> Vec<Dir> {
     }
 }
 
-fn step(p: Point, dir: Dir) -> Point {
-    use Dir::*;
-    match dir {
-        Up => Point{ row: p.row - 1, col: p.col},
-        Down => Point{ row: p.row + 1, col: p.col},
-        Right => Point{ row: p.row, col: p.col + 1},
-        Left => Point{ row: p.row, col: p.col - 1},
-    }
-}
-
 fn search(grid: &Grid<Square>, start_point: Point, start_dir: Dir) -> usize {
     let mut stack = Vec::new();
     stack.push((start_point, start_dir));
@@ -77,9 +52,7 @@ fn search(grid: &Grid<Square>, start_point: Point, start_dir: Dir) -> usize {
     let mut visited: HashSet<(Point, Dir)> = HashSet::new();
     visited.insert((start_point, start_dir));
 
-    while !stack.is_empty() {
-        let (p, dir) = stack.pop().unwrap();
-
+    while let Some((p, dir)) = stack.pop() {
         let square = grid.getp(&p);
         for new_dir in interact(dir, *square) {
             let new_p = step(p, new_dir);
@@ -97,15 +70,7 @@ fn search(grid: &Grid<Square>, start_point: Point, start_dir: Dir) -> usize {
             visited.contains(&(p, Dir::Down))
     };
 
-    let mut count = 0;
-    for row in 0..grid.height() {
-        for col in 0..grid.width() {
-            if visited_point(Point{row, col}) {
-                count += 1;
-            }
-        }
-    }
-    count
+    grid.iter_points().filter(|(p, _)| visited_point(*p)).count()
 }
 
 fn maximize_count(grid: &Grid<Square>) -> usize {
@@ -123,9 +88,25 @@ fn maximize_count(grid: &Grid<Square>) -> usize {
     *counts.iter().max().unwrap()
 }
 
-fn main() {
-    let grid = parse_input();
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = Grid<Square>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        search(grid, Point{ row: 0, col: 0 }, Dir::Right).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        maximize_count(grid).into()
+    }
+}
 
-    println!("{}", search(&grid, Point{ row: 0, col: 0 }, Dir::Right));
-    println!("{}", maximize_count(&grid));
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day16, "16", part1: ["example1"], part2: ["example1"]);
 }
@@ -0,0 +1,127 @@
+use crate::dir::{step, Dir};
+use crate::grid::{Grid, Point};
+use crate::search::{explore_dijkstra, Explored};
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+
+fn parse_input(input: &str) -> Result<Grid<i64>> {
+    Grid::parse(input, |ch| {
+        ch.to_digit(10)
+            .map(|digit| digit as i64)
+            .ok_or_else(|| Error::new(format!("Expected a digit, got {:?}", ch)))
+    })
+}
+
+fn possible_dirs(in_dir: Dir, steps: usize, min: usize, max: usize) -> Vec<Dir> {
+    if steps < min {
+        return vec![in_dir];
+    }
+    if steps >= max {
+        vec![in_dir.cw(), in_dir.ccw()]
+    } else {
+        vec![in_dir, in_dir.cw(), in_dir.ccw()]
+    }
+}
+
+// A state is the crucible's position, the direction it's moving in, and how many steps it has
+// taken in that direction.
+pub type State = (Point, Dir, usize);
+
+fn search(grid: &Grid<i64>, min_steps: usize, max_steps: usize, keep_path: bool) -> Explored<State> {
+    let end = Point{ row: grid.height() - 1, col: grid.width() - 1 };
+    let successors = |&(pos, dir, steps): &State| {
+        possible_dirs(dir, steps, min_steps, max_steps)
+            .into_iter()
+            .map(move |d| (step(pos, d), d, if d != dir { 1 } else { steps + 1 }))
+            .filter(|(p, _, _)| grid.containsp(p))
+            .map(|state| (state, *grid.getp(&state.0)))
+    };
+    // The crucible can't stop at the end before it has moved the minimum number of steps.
+    let at_end = |&(pos, _, steps): &State| pos == end && steps >= min_steps;
+
+    explore_dijkstra((Point{ row: 0, col: 0 }, Dir::Right, 0), successors, at_end, keep_path)
+}
+
+// The states along the route with the least heat loss, from the start to the end.
+pub fn best_route(grid: &Grid<i64>, min_steps: usize, max_steps: usize) -> Option<Vec<State>> {
+    search(grid, min_steps, max_steps, true).path()
+}
+
+// The grid with the route drawn on it, for checking how the step limits were handled.
+pub fn render_route(grid: &Grid<i64>, route: &[State]) -> Grid<char> {
+    let path: Vec<(Point, Dir)> = route.iter().map(|&(p, dir, _)| (p, dir)).collect();
+    grid.render(|&n| std::char::from_digit(n as u32, 10).unwrap_or('?')).with_path(&path)
+}
+
+fn min_heat_loss(grid: &Grid<i64>, min_steps: usize, max_steps: usize) -> i64 {
+    search(grid, min_steps, max_steps, false).cost().expect("No route to the end of the grid")
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = Grid<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        min_heat_loss(grid, 0, 3).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        min_heat_loss(grid, 4, 10).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(super::Day17, "17", part1: ["example1"], part2: ["example1", "example2"]);
+
+    #[test]
+    fn test_best_route() {
+        let grid = parse_input(include_str!("../../inputs/day17/example1.txt")).unwrap();
+        let expected = [(0, 3, 102, "\
+>>>34^>>>1323
+32v>>>35v>623
+325524565v>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>"), (4, 10, 94, "\
+>>>>>>>>>1323
+32154535v5623
+32552456v4254
+34465858v5452
+45466578v>>>>
+143859879845v
+445787698776v
+363787797965v
+465496798688v
+456467998645v
+122468686556v
+254654888773v
+432267465553v")];
+        for &(min_steps, max_steps, heat_loss, rendered) in &expected {
+            let route = best_route(&grid, min_steps, max_steps).unwrap();
+            assert_eq!(route[1..].iter().map(|(p, _, _)| grid.getp(p)).sum::<i64>(), heat_loss);
+            // Each straight run is within the limits, including the last one into the end.
+            for (i, &(_, dir, steps)) in route.iter().enumerate().skip(1) {
+                assert!(steps <= max_steps);
+                if route.get(i + 1).is_none_or(|&(_, next, _)| next != dir) {
+                    assert!(steps >= min_steps);
+                }
+            }
+            assert_eq!(render_route(&grid, &route).to_string(), rendered);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use regex::Regex;
+use crate::dir::Dir;
+use crate::geometry::{enclosed_points, trace_moves};
+use crate::grid::Point;
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+use crate::utils::{captures, parse_lines, parse_num};
+
+fn parse_input1(input: &str) -> Result<Vec<(Dir, i64)>> {
+    let pattern = Regex::new("([RULD]) (\\d+) \\(#([0-9a-f]{6})\\)").unwrap();
+    parse_lines(input, |line| {
+        let c = captures(&pattern, line)?;
+        let (_, [dir, steps, _colour]) = c.extract();
+        Ok((dir.parse()?, parse_num(steps)?))
+    })
+}
+
+fn parse_hex(s: &str) -> i64 {
+    // Only called on strings the pattern has already checked to be hex digits.
+    s.chars().fold(0, |ret, ch| ret * 16 + ch.to_digit(16).unwrap() as i64)
+}
+
+fn parse_input2(input: &str) -> Result<Vec<(Dir, i64)>> {
+    let pattern = Regex::new("([RULD]) (\\d+) \\(#([0-9a-f]{6})\\)").unwrap();
+    parse_lines(input, |line| {
+        let c = captures(&pattern, line)?;
+        let (_, [_dir, _steps, colour]) = c.extract();
+        let d = match colour.chars().last().unwrap() {
+            '0' => Dir::Right,
+            '1' => Dir::Down,
+            '2' => Dir::Left,
+            '3' => Dir::Up,
+            ch => return Err(Error::new(format!("Bad direction digit {:?} in colour", ch))),
+        };
+        let steps = parse_hex(&colour[..colour.len() - 1]);
+        Ok((d, steps))
+    })
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    // The corners of the trench for each part.
+    type Input = (Vec<Point>, Vec<Point>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((trace_moves(&parse_input1(input)?)?, trace_moves(&parse_input2(input)?)?))
+    }
+
+    fn part1((trench1, _): &Self::Input) -> Answer {
+        enclosed_points(trench1).into()
+    }
+
+    fn part2((_, trench2): &Self::Input) -> Answer {
+        enclosed_points(trench2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day18, "18", part1: ["example1"], part2: ["example1"]);
+}
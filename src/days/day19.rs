@@ -1,6 +1,9 @@
 use regex::Regex;
 use std::collections::HashMap;
-use aoc2023::utils::stdio_lines;
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+use crate::interval::{BoxN, Interval};
+use crate::utils::{captures, parse_num};
 
 #[derive(Eq, PartialEq)]
 enum Decision {
@@ -27,13 +30,13 @@ enum Rule {
     Finish(Decision),
 }
 
-struct Workflow {
+pub struct Workflow {
     name: String,
     rules: Vec<Rule>,
 }
 
 #[derive(Clone)]
-struct Part {
+pub struct Part {
     x: i64,
     m: i64,
     a: i64,
@@ -50,25 +53,20 @@ fn parse_decision(s: &str) -> Decision {
     }
 }
 
-fn parse_workflow(line: &str) -> Workflow {
+fn parse_workflow(line: &str) -> Result<Workflow> {
     let workflow_pattern = Regex::new("([a-zA-Z]+)\\{(.*)}").unwrap();
     let rule_pattern = Regex::new("([xmas])([<>])([-0-9]+):([A-Za-z]+)").unwrap();
 
-    let cap = workflow_pattern.captures(&line).unwrap();
+    let cap = captures(&workflow_pattern, line)?;
     let [name, rulestr] = cap.extract().1;
     let mut rules = Vec::new();
     for part in rulestr.split(',') {
-        let cap = rule_pattern.captures(part);
-        if cap.is_some() {
-            let [var, op, limit, decision] = cap.unwrap().extract().1;
+        if let Some(cap) = rule_pattern.captures(part) {
+            let [var, op, limit, decision] = cap.extract().1;
             let rule = BranchRule {
                 var: String::from(var),
-                op: match op {
-                    "<" => Op::LT,
-                    ">" => Op::GT,
-                    _ => panic!("Bad op")
-                },
-                limit: limit.parse().unwrap(),
+                op: if op == "<" { Op::LT } else { Op::GT },
+                limit: parse_num(limit)?,
                 decision: parse_decision(decision),
             };
             rules.push(Rule::Branch(rule));
@@ -76,39 +74,65 @@ fn parse_workflow(line: &str) -> Workflow {
             rules.push(Rule::Finish(parse_decision(part)));
         }
     }
-    Workflow{ name: String::from(name), rules }
+    // Without a plain target at the end, a part matching none of the conditions has nowhere to go.
+    if !matches!(rules.last(), Some(Rule::Finish(_))) {
+        return Err(Error::new(format!("Workflow {} must end with a plain target", name)));
+    }
+    Ok(Workflow{ name: String::from(name), rules })
 }
 
-fn parse_part(line: &str) -> Part {
+fn parse_part(line: &str) -> Result<Part> {
     let pattern = Regex::new("\\{x=([-0-9]+),m=([-0-9]+),a=([-0-9]+),s=([-0-9]+)}").unwrap();
-    let cap = pattern.captures(line).unwrap();
+    let cap = captures(&pattern, line)?;
     let [x,m,a,s] = cap.extract().1;
-    Part {
-        x: x.parse().unwrap(),
-        m: m.parse().unwrap(),
-        a: a.parse().unwrap(),
-        s: s.parse().unwrap(),
+    Ok(Part {
+        x: parse_num(x)?,
+        m: parse_num(m)?,
+        a: parse_num(a)?,
+        s: parse_num(s)?,
+    })
+}
+
+// Every workflow that is forwarded to must exist, starting with "in".
+fn check_targets(workflows: &HashMap<String, Workflow>) -> Result<()> {
+    let known = |name: &str| workflows.contains_key(name);
+    if !known("in") {
+        return Err(Error::new("No workflow named \"in\""));
+    }
+    for workflow in workflows.values() {
+        for rule in &workflow.rules {
+            let decision = match rule {
+                Rule::Branch(branch) => &branch.decision,
+                Rule::Finish(decision) => decision,
+            };
+            if let Decision::Forward(target) = decision {
+                if !known(target) {
+                    return Err(Error::new(format!("Workflow {} forwards to unknown workflow {}", workflow.name, target)));
+                }
+            }
+        }
     }
+    Ok(())
 }
 
-fn parse_input() -> (HashMap<String, Workflow>, Vec<Part>) {
+fn parse_input(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>)> {
     let mut workflows = HashMap::new();
 
-    let lines = stdio_lines();
-    for i in 0..lines.len() {
-        if lines[i].len() == 0 {
-            break;
-        }
-        let workflow = parse_workflow(&lines[i]);
+    let lines: Vec<&str> = input.lines().collect();
+    let blank = lines.iter().position(|line| line.is_empty())
+        .ok_or_else(|| Error::new("Expected a blank line between the workflows and the parts"))?;
+    for (i, line) in lines[..blank].iter().enumerate() {
+        let workflow = parse_workflow(line).map_err(|e| e.at_line(i + 1))?;
         workflows.insert(workflow.name.clone(), workflow);
     }
+    check_targets(&workflows)?;
 
     let mut parts = Vec::new();
-    for line in &lines[workflows.len() + 1..] {
-        parts.push(parse_part(line));
+    for (i, line) in lines.iter().enumerate().skip(blank + 1) {
+        parts.push(parse_part(line).map_err(|e| e.at_line(i + 1))?);
     }
 
-    (workflows, parts)
+    Ok((workflows, parts))
 }
 
 fn resolve(workflows: &HashMap<String, Workflow>, part: &Part) -> Decision {
@@ -153,60 +177,35 @@ fn resolve(workflows: &HashMap<String, Workflow>, part: &Part) -> Decision {
     }
 }
 
-#[derive(Clone)]
-struct PartsRange {
-    minx: i64,
-    maxx: i64,
-    minm: i64,
-    maxm: i64,
-    mina: i64,
-    maxa: i64,
-    mins: i64,
-    maxs: i64,
-}
-
-fn empty(range: &PartsRange) -> bool {
-    !(range.minx <= range.maxx && range.minm <= range.maxm && range.mina <= range.maxa && range.mins <= range.maxs)
-}
+// The possible values of x, m, a and s, in that order.
+type PartsRange = BoxN<4>;
 
-fn cut_range(range: &PartsRange, var: &str, op: Op, limit: i64) -> (PartsRange, PartsRange) {
-    let PartsRange {minx, maxx, minm, maxm, mina, maxa, mins, maxs} = *range;
-    match (var, op) {
-        ("x", Op::LT) => (PartsRange{ minx, maxx: limit - 1, minm, maxm, mina, maxa, mins, maxs },
-                          PartsRange{ minx: limit, maxx, minm, maxm, mina, maxa, mins, maxs }),
-        ("x", Op::GT) => (PartsRange{ minx: limit + 1, maxx, minm, maxm, mina, maxa, mins, maxs },
-                          PartsRange{ minx, maxx: limit, minm, maxm, mina, maxa, mins, maxs }),
-        ("m", Op::LT) => (PartsRange{ minx, maxx, minm, maxm: limit - 1, mina, maxa, mins, maxs },
-                          PartsRange{ minx, maxx, minm: limit, maxm, mina, maxa, mins, maxs }),
-        ("m", Op::GT) => (PartsRange{ minx, maxx, minm: limit + 1, maxm, mina, maxa, mins, maxs },
-                          PartsRange{ minx, maxx, minm, maxm: limit, mina, maxa, mins, maxs }),
-        ("a", Op::LT) => (PartsRange{ minx, maxx, minm, maxm, mina, maxa: limit - 1, mins, maxs },
-                          PartsRange{ minx, maxx, minm, maxm, mina: limit, maxa, mins, maxs }),
-        ("a", Op::GT) => (PartsRange{ minx, maxx, minm, maxm, mina: limit + 1, maxa, mins, maxs },
-                          PartsRange{ minx, maxx, minm, maxm, mina, maxa: limit, mins, maxs }),
-        ("s", Op::LT) => (PartsRange{ minx, maxx, minm, maxm, mina, maxa, mins, maxs: limit - 1 },
-                          PartsRange{ minx, maxx, minm, maxm, mina, maxa, mins: limit, maxs }),
-        ("s", Op::GT) => (PartsRange{ minx, maxx, minm, maxm, mina, maxa, mins: limit + 1, maxs },
-                          PartsRange{ minx, maxx, minm, maxm, mina, maxa, mins, maxs: limit }),
+fn var_index(var: &str) -> usize {
+    match var {
+        "x" => 0,
+        "m" => 1,
+        "a" => 2,
+        "s" => 3,
         _ => panic!("Bad range cut"),
     }
 }
 
-fn combination_count(range: &PartsRange) -> i64 {
-    (range.maxx - range.minx + 1) *
-        (range.maxm - range.minm + 1) *
-        (range.maxa - range.mina + 1) *
-        (range.maxs - range.mins + 1)
+// The parts of the range that match and don't match the condition.
+fn cut_range(range: &PartsRange, var: &str, op: Op, limit: i64) -> (PartsRange, PartsRange) {
+    match op {
+        Op::LT => range.split_by(var_index(var), |v| v < limit),
+        Op::GT => range.split_by(var_index(var), |v| v > limit),
+    }
 }
 
 fn combinations(workflows: &HashMap<String, Workflow>, flow: &String, range: &PartsRange) -> i64 {
-    let mut remaining_range = range.clone();
+    let mut remaining_range = *range;
 
     let workflow = workflows.get(flow).unwrap();
     let mut ret = 0;
     for rule in &workflow.rules {
         if let Rule::Finish(Decision::Accept) = rule {
-            ret += combination_count(&remaining_range);
+            ret += remaining_range.volume();
             break;
         } else if let Rule::Finish(Decision::Reject) = rule {
             break;
@@ -215,14 +214,14 @@ fn combinations(workflows: &HashMap<String, Workflow>, flow: &String, range: &Pa
             break;
         } else if let Rule::Branch(BranchRule{ var, op, limit, decision}) = rule {
             let (true_branch, false_branch) = cut_range(&remaining_range, var, *op, *limit);
-            if !empty(&true_branch) {
+            if !true_branch.is_empty() {
                 if let Decision::Accept = decision {
-                    ret += combination_count(&true_branch);
+                    ret += true_branch.volume();
                 } else if let Decision::Forward(next) = decision {
                     ret += combinations(workflows, next, &true_branch);
                 }
             }
-            if empty(&false_branch) {
+            if false_branch.is_empty() {
                 break;
             }
             remaining_range = false_branch;
@@ -233,20 +232,39 @@ fn combinations(workflows: &HashMap<String, Workflow>, flow: &String, range: &Pa
     ret
 }
 
-fn main() {
-    let (workflows, parts) = parse_input();
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((workflows, parts): &Self::Input) -> Answer {
+        parts.iter()
+            .filter(|&p| resolve(workflows, p) == Decision::Accept)
+            .map(|p| p.x + p.m + p.a + p.s)
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2((workflows, _): &Self::Input) -> Answer {
+        let range = BoxN::new([Interval::inclusive(1, 4000); 4]);
+        combinations(workflows, &String::from("in"), &range).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let accepted: Vec<Part> = parts.iter()
-        .filter(|&p| resolve(&workflows, p) == Decision::Accept)
-        .cloned()
-        .collect();
-    println!("{}", accepted.iter().map(|p| p.x + p.m + p.a + p.s).sum::<i64>());
+    crate::example_tests!(super::Day19, "19", part1: ["example1"], part2: ["example1"]);
 
-    let range = PartsRange{
-        minx: 1, maxx: 4000,
-        minm: 1, maxm: 4000,
-        mina: 1, maxa: 4000,
-        mins: 1, maxs: 4000,
-    };
-    println!("{}", combinations(&workflows, &String::from("in"), &range));
+    #[test]
+    fn test_parse_workflow() {
+        assert_eq!(parse_workflow("in{x<5:A,R}").unwrap().rules.len(), 2);
+        let err = parse_workflow("in{x<5:A}").err().unwrap();
+        assert_eq!(err.to_string(), "Workflow in must end with a plain target");
+    }
 }
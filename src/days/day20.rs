@@ -1,5 +1,7 @@
-use aoc2023::utils::stdio_lines;
-use aoc2023::numeric::lcm;
+use crate::numeric::lcm;
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+use crate::utils::captures;
 use std::collections::HashMap;
 use regex::Regex;
 use std::collections::VecDeque;
@@ -12,20 +14,19 @@ enum GateState {
 }
 
 #[derive(Clone)]
-struct Gate {
+pub struct Gate {
     outputs: Vec<String>,
     gate_state: GateState,
 }
 
-type GateMap = HashMap<String, Gate>;
+pub type GateMap = HashMap<String, Gate>;
 
-fn parse_input() -> GateMap {
+fn parse_input(input: &str) -> Result<GateMap> {
     let pattern = Regex::new("([%&]?)([a-z]+) -> (.*)").unwrap();
     let mut nodes = Vec::new();
     let mut inputs: HashMap<&str, Vec<&str>> = HashMap::new();
-    let lines = stdio_lines();
-    for line in &lines {
-        let cap = pattern.captures(&line).unwrap();
+    for (i, line) in input.lines().enumerate() {
+        let cap = captures(&pattern, line).map_err(|e| e.at_line(i + 1))?;
         let (_, [tp, name, output_str]) = cap.extract();
         let outputs: Vec<&str> = output_str.split(", ").collect();
 
@@ -55,7 +56,7 @@ fn parse_input() -> GateMap {
             }
         );
     }
-    ret
+    Ok(ret)
 }
 
 type Pulse = bool;
@@ -133,7 +134,7 @@ fn find_key_nodes(gates: &GateMap, out: &str) -> Option<Vec<String>> {
     }
     let root = gates.get(&layer1[0]).unwrap();
     if let GateState::Conjunction(inputs) = &root.gate_state {
-        Some(inputs.iter().map(|(name, _)| name.clone()).collect())
+        Some(inputs.keys().cloned().collect())
     } else {
         None
     }
@@ -180,17 +181,33 @@ fn find_first_activation(gates: &GateMap, out: &str) -> usize {
     lcm_all(&counts)
 }
 
-fn main() {
-    let input_gates = parse_input();
+pub struct Day20;
 
-    let mut gates = input_gates.clone();
-    let mut low = 0;
-    let mut high = 0;
-    for _ in 0..1000 {
-        let (l, h) = run(&mut gates);
-        low += l;
-        high += h;
+impl Solver for Day20 {
+    type Input = GateMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
     }
-    println!("{}", low * high);
-    println!("{}", find_first_activation(&input_gates, "rx"));
+
+    fn part1(input_gates: &Self::Input) -> Answer {
+        let mut gates = input_gates.clone();
+        let mut low = 0;
+        let mut high = 0;
+        for _ in 0..1000 {
+            let (l, h) = run(&mut gates);
+            low += l;
+            high += h;
+        }
+        (low * high).into()
+    }
+
+    fn part2(input_gates: &Self::Input) -> Answer {
+        find_first_activation(input_gates, "rx").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day20, "20", part1: ["example1", "example2"]);
 }
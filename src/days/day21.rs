@@ -0,0 +1,86 @@
+use crate::grid::{Grid, GridView, Point};
+use crate::dir::cart_neighbours;
+use crate::search::explore_bfs;
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+
+fn parse_input(input: &str) -> Result<(Grid<char>, Point)> {
+    let mut grid: Grid<char> = input.parse()?;
+    let start = grid.iter_points()
+        .find(|(_, &ch)| ch == 'S')
+        .map(|(p, _)| p)
+        .ok_or_else(|| Error::new("No starting tile 'S' in the grid"))?;
+    *grid.mutgetp(&start) = '.';
+    Ok((grid, start))
+}
+
+// Count the plots first reached at each number of steps with the same parity as max_steps,
+// indexed by half the number of steps.
+fn search_reachable<G: GridView<char>>(grid: &G, start: &Point, max_steps: usize) -> Vec<usize> {
+    // Nothing further away than max_steps can be reached, which keeps the search finite even
+    // on a wrapping grid.
+    let in_range = |p: &Point| (*p - *start).manhattan() as usize <= max_steps;
+    let successors = |pos: &Point| {
+        cart_neighbours(pos).into_iter()
+            .filter(|n| in_range(n) && grid.at(n) == Some(&'.'))
+            .collect::<Vec<_>>()
+    };
+    let explored = explore_bfs(*start, successors, |_| false, false);
+
+    let mut ret = vec![0; max_steps / 2 + 1];
+    for &steps in explored.distances().values() {
+        let steps = steps as usize;
+        if steps <= max_steps && steps % 2 == max_steps % 2 {
+            ret[steps / 2] += 1;
+        }
+    }
+    ret
+}
+
+fn predict_output(grid: &Grid<char>, start: &Point, max_steps: usize) -> usize {
+    let n = max_steps.div_ceil(2);
+    const PERIOD: usize = 131; // Actually half of the "real" period.
+
+    let loops = n / PERIOD - 1;
+    let offset = (n % PERIOD) + PERIOD;
+
+    let x = search_reachable(&grid.wrapping(), start, 1200 + (max_steps % 2));
+
+    // The data in x grows as x_i+P = x_i + delta_i, or x_i+rP = x_i + r*delta_i
+    // We want the sum of the first n terms of x.
+    x[0..offset].iter().sum::<usize>() +
+        loops * x[offset..offset + PERIOD].iter().sum::<usize>() +
+        (loops - 1) * loops / 2 * ((offset..offset + PERIOD).map(|i| x[i + 2*PERIOD] - x[i + PERIOD]).sum::<usize>())
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = (Grid<char>, Point);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((grid, start): &Self::Input) -> Answer {
+        search_reachable(grid, start, 64).iter().sum::<usize>().into()
+    }
+
+    fn part2((grid, start): &Self::Input) -> Answer {
+        const N: usize = 26501365;
+        predict_output(grid, start, N).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example doesn't use the puzzle's step counts, so there is nothing for the answers file.
+    #[test]
+    fn test_search_reachable() {
+        let (grid, start) = parse_input(include_str!("../../inputs/day21/example1.txt")).unwrap();
+        assert_eq!(search_reachable(&grid, &start, 6).iter().sum::<usize>(), 16);
+        assert_eq!(search_reachable(&grid.wrapping(), &start, 100).iter().sum::<usize>(), 6536);
+    }
+}
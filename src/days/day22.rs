@@ -0,0 +1,139 @@
+use std::cmp::{min, max};
+use crate::interval::{BoxN, Interval};
+use crate::utils::{grab_numbers, parse_lines};
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+use crate::graph::DominatorTree;
+use crate::grid::{Grid, Point};
+
+// The cubes of a brick, with the axes x, y and z.
+pub type Brick = BoxN<3>;
+
+const X: usize = 0;
+const Y: usize = 1;
+const Z: usize = 2;
+
+fn parse_brick(line: &str) -> Result<Brick> {
+    let nums = grab_numbers(line)?;
+    let [x0, y0, z0, x1, y1, z1] = nums[..] else {
+        return Err(Error::new(format!("Expected 6 numbers for a brick, got {}", nums.len())));
+    };
+    let axis = |a: i64, b: i64| Interval::inclusive(min(a, b), max(a, b));
+    Ok(BoxN::new([axis(x0, x1), axis(y0, y1), axis(z0, z1)]))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Brick>> {
+    let mut ret = parse_lines(input, parse_brick)?;
+    ret.sort_by_key(|b| b.axes[Z].start);
+    Ok(ret)
+}
+
+// The bricks once they have settled, and which bricks rest on which. The bricks keep their order
+// by starting height, so a brick always comes after the bricks supporting it.
+pub struct Stack {
+    pub bricks: Vec<Brick>,
+    pub supporting: Vec<Vec<usize>>,
+    pub supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    // Drop each brick straight to its resting place, keeping track of the top of the stack and
+    // the brick there for every x, y position.
+    pub fn settle(bricks: &[Brick]) -> Stack {
+        let (x_min, x_max) = span(bricks, X);
+        let (y_min, y_max) = span(bricks, Y);
+        let mut heights = Grid::from_dim((y_max - y_min) as i32, (x_max - x_min) as i32, (1, None));
+
+        let mut stack = Stack{ bricks: Vec::new(), supporting: Vec::new(), supported_by: Vec::new() };
+        for (i, brick) in bricks.iter().enumerate() {
+            let footprint: Vec<Point> = brick_positions(brick, X)
+                .flat_map(|x| brick_positions(brick, Y).map(move |y| Point{ row: (y - y_min) as i32, col: (x - x_min) as i32 }))
+                .collect();
+            let floor = footprint.iter().map(|p| heights.getp(p).0).max().unwrap_or(1);
+
+            let mut supp: Vec<usize> = footprint.iter()
+                .filter_map(|p| match *heights.getp(p) {
+                    (z, Some(k)) if z == floor => Some(k),
+                    _ => None,
+                })
+                .collect();
+            supp.sort_unstable();
+            supp.dedup();
+            for &k in &supp {
+                stack.supporting[k].push(i);
+            }
+
+            let settled = brick.shift(Z, floor - brick.axes[Z].start);
+            for p in &footprint {
+                *heights.mutgetp(p) = (settled.axes[Z].end, Some(i));
+            }
+            stack.bricks.push(settled);
+            stack.supporting.push(Vec::new());
+            stack.supported_by.push(supp);
+        }
+        stack
+    }
+
+    pub fn len(&self) -> usize {
+        self.bricks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bricks.is_empty()
+    }
+
+    // Whether every brick resting on this one also rests on another.
+    pub fn can_be_disintegrated(&self, i: usize) -> bool {
+        self.supporting[i].iter().all(|&j| self.supported_by[j].len() > 1)
+    }
+
+    // The number of other bricks that fall when each brick is removed. With the ground as an
+    // extra node holding up the bricks resting on it, a brick falls when one of its dominators
+    // is removed.
+    pub fn fall_counts(&self) -> Vec<usize> {
+        let ground = self.len();
+        let mut successors = self.supporting.clone();
+        successors.push((0..self.len()).filter(|&i| self.supported_by[i].is_empty()).collect());
+        let tree = DominatorTree::new(&successors, ground);
+        tree.subtree_sizes()[..ground].iter().map(|size| size - 1).collect()
+    }
+}
+
+// The smallest start and largest end on an axis, over all bricks.
+fn span(bricks: &[Brick], axis: usize) -> (i64, i64) {
+    let start = bricks.iter().map(|b| b.axes[axis].start).min().unwrap_or(0);
+    let end = bricks.iter().map(|b| b.axes[axis].end).max().unwrap_or(0);
+    (start, end)
+}
+
+fn brick_positions(brick: &Brick, axis: usize) -> std::ops::Range<i64> {
+    brick.axes[axis].start..brick.axes[axis].end
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(floating_bricks: &Self::Input) -> Answer {
+        let stack = Stack::settle(floating_bricks);
+        (0..stack.len())
+            .filter(|&i| stack.can_be_disintegrated(i))
+            .count()
+            .into()
+    }
+
+    fn part2(floating_bricks: &Self::Input) -> Answer {
+        let stack = Stack::settle(floating_bricks);
+        stack.fall_counts().iter().sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day22, "22", part1: ["example1"], part2: ["example1"]);
+}
@@ -0,0 +1,200 @@
+use std::cmp::max;
+use crate::grid::{Point, Grid};
+use crate::graph::{compress_grid_directed, Graph};
+use crate::dir::Dir;
+use crate::solver::{Answer, Solver};
+use crate::error::Result;
+use std::thread;
+
+// The junctions of the map, along with the nodes for the start and the end.
+fn build_graph(map: &Grid<char>, slippery: bool) -> (Graph<Point>, usize, usize) {
+    use Dir::*;
+    let start = Point{ row: 0, col: 1};
+    let end = Point{ row: map.height() - 1, col: map.width() - 2 };
+
+    let can_move = |p: Point, dir: Dir| match (map.getp(&p), dir) {
+        ('^', Up) | ('v', Down) | ('<', Left) | ('>', Right) => true,
+        ('^', _) | ('v', _) | ('<', _) | ('>', _) => !slippery,
+        _ => true,
+    };
+    let graph = compress_grid_directed(map, |&c| c != '#', can_move, &[start, end]);
+    let (s, e) = (graph.index_of(&start).unwrap(), graph.index_of(&end).unwrap());
+    (graph, s, e)
+}
+
+struct State {
+    node_id: usize,
+    next_child: usize,
+    length: usize,
+}
+
+// The longest path from start to end that visits no node twice, by a plain depth first search.
+// Works for any number of nodes, but is much slower than Junctions::longest_path.
+fn longest_path_dfs(graph: &Graph<Point>, start: usize, end: usize) -> usize {
+    let mut longest_path = 0usize;
+
+    let mut stack = Vec::new();
+    stack.push(State{ node_id: start, next_child: 0, length: 0});
+
+    let mut visited = vec![false; graph.len()];
+    visited[start] = true;
+
+    while let Some(&State{node_id, next_child, length}) = stack.last() {
+        let edges = graph.edges(node_id);
+        if node_id == end {
+            longest_path = max(longest_path, length);
+        }
+        if node_id == end || next_child == edges.len() {
+            visited[node_id] = false;
+            stack.pop();
+        } else {
+            let (next, weight) = edges[next_child];
+            stack.last_mut().unwrap().next_child += 1;
+            if !visited[next] {
+                visited[next] = true;
+                stack.push(State{ node_id: next, next_child: 0, length: length + weight});
+            }
+        }
+    }
+
+    longest_path
+}
+
+// The graph as a dense adjacency matrix, for searching with the visited nodes in a bitmask.
+struct Junctions {
+    size: usize,
+    // The length of the edge from i to j is at weights[i * size + j], with 0 for no edge.
+    weights: Vec<usize>,
+    neighbours: Vec<Vec<usize>>,
+    end: usize,
+    // The only junction leading to the end, and the length of that last edge. Once a path gets
+    // there it has to go straight to the end, since the end could never be reached otherwise.
+    last: Option<(usize, usize)>,
+}
+
+impl Junctions {
+    const MAX_SIZE: usize = 64;
+
+    fn new(graph: &Graph<Point>, end: usize) -> Option<Junctions> {
+        let size = graph.len();
+        if size > Junctions::MAX_SIZE {
+            return None;
+        }
+        let mut weights = vec![0; size * size];
+        let mut neighbours = vec![Vec::new(); size];
+        for (from, ns) in neighbours.iter_mut().enumerate() {
+            for &(to, weight) in graph.edges(from) {
+                let w = &mut weights[from * size + to];
+                if *w == 0 {
+                    ns.push(to);
+                }
+                *w = max(*w, weight);
+            }
+        }
+        let into_end: Vec<usize> = (0..size).filter(|&i| weights[i * size + end] > 0).collect();
+        let last = match into_end[..] {
+            [i] => Some((i, weights[i * size + end])),
+            _ => None,
+        };
+        Some(Junctions{ size, weights, neighbours, end, last })
+    }
+
+    // The longest way on to the end from a node reached with the given visited nodes and length.
+    // Caching results by (node, visited) doesn't pay: with 36 junctions there are millions of
+    // distinct states, each seen only a few times, so the cache costs more than it saves.
+    fn search(&self, node: usize, visited: u64, length: usize) -> Option<usize> {
+        if node == self.end {
+            return Some(length);
+        }
+        if let Some((last, weight)) = self.last {
+            if node == last {
+                return Some(length + weight);
+            }
+        }
+        self.neighbours[node].iter()
+            .filter(|&&next| visited & 1 << next == 0)
+            .filter_map(|&next| self.search(next, visited | 1 << next, length + self.weights[node * self.size + next]))
+            .max()
+    }
+
+    // The paths of a few steps from the start, as (node, visited, length), which together cover
+    // every path to the end.
+    fn branches(&self, start: usize, steps: usize) -> Vec<(usize, u64, usize)> {
+        let mut branches = vec![(start, 1u64 << start, 0)];
+        for _ in 0..steps {
+            let mut next_branches = Vec::new();
+            for (node, visited, length) in branches {
+                if node == self.end || self.last.is_some_and(|(last, _)| last == node) {
+                    next_branches.push((node, visited, length));
+                    continue;
+                }
+                for &next in &self.neighbours[node] {
+                    if visited & 1 << next == 0 {
+                        next_branches.push((next, visited | 1 << next, length + self.weights[node * self.size + next]));
+                    }
+                }
+            }
+            branches = next_branches;
+        }
+        branches
+    }
+
+    // Optionally searches the first few branches from the start on separate threads.
+    fn longest_path(&self, start: usize, parallel: bool) -> usize {
+        if !parallel {
+            return self.search(start, 1 << start, 0).unwrap_or(0);
+        }
+        let branches = self.branches(start, 3);
+        thread::scope(|s| {
+            let handles: Vec<_> = branches.iter()
+                .map(|&(node, visited, length)| s.spawn(move || self.search(node, visited, length)))
+                .collect();
+            handles.into_iter().filter_map(|h| h.join().unwrap()).max().unwrap_or(0)
+        })
+    }
+}
+
+fn longest_path(map: &Grid<char>, slippery: bool, parallel: bool) -> usize {
+    let (graph, start, end) = build_graph(map, slippery);
+    match Junctions::new(&graph, end) {
+        Some(junctions) => junctions.longest_path(start, parallel),
+        None => longest_path_dfs(&graph, start, end),
+    }
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        longest_path(map, true, false).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        longest_path(map, false, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day23, "23", part1: ["example1"], part2: ["example1"]);
+
+    use super::*;
+
+    #[test]
+    fn test_search_strategies() {
+        let map = include_str!("../../inputs/day23/example1.txt").parse().unwrap();
+        for slippery in [true, false] {
+            let (graph, start, end) = build_graph(&map, slippery);
+            let expected = longest_path_dfs(&graph, start, end);
+            let junctions = Junctions::new(&graph, end).unwrap();
+            assert_eq!(junctions.longest_path(start, false), expected);
+            assert_eq!(junctions.longest_path(start, true), expected);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::geometry::{intersect_lines_xy, Vec3};
+use crate::point::Point3;
+use crate::utils::{grab_numbers, parse_lines};
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Zero;
+
+fn parse_input(input: &str) -> Result<Vec<(Point3, Point3)>> {
+    parse_lines(input, |line| {
+        let nums = grab_numbers(line)?;
+        let [x, y, z, vx, vy, vz] = nums[..] else {
+            return Err(Error::new(format!("Expected 6 numbers for a hailstone, got {}", nums.len())));
+        };
+        Ok((Point3::new(x, y, z), Point3::new(vx, vy, vz)))
+    })
+}
+
+fn rational(n: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(n))
+}
+
+fn exact(p: Point3) -> Vec3<BigRational> {
+    Vec3::from(p).map(rational)
+}
+
+// The number of pairs of hailstones whose paths cross in the xy plane inside the test area,
+// going forward in time for both.
+fn count_crossings(hailstones: &[(Point3, Point3)], min: i64, max: i64) -> usize {
+    let lines: Vec<_> = hailstones.iter().map(|&(p, v)| (exact(p), exact(v))).collect();
+    let inside = |x: &BigRational| rational(min) <= *x && *x <= rational(max);
+
+    let mut count = 0;
+    for (i, (p0, v0)) in lines.iter().enumerate() {
+        for (p1, v1) in &lines[i + 1..] {
+            if let Some((t0, t1)) = intersect_lines_xy(p0, v0, p1, v1) {
+                let at = p0.clone() + v0.clone() * t0.clone();
+                if t0 >= BigRational::zero() && t1 >= BigRational::zero() && inside(&at.x) && inside(&at.y) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+// For part 2, look at everything relative to the first hailstone, which then stands still at the
+// origin. The rock has to pass through the origin, so its path lies in the plane through the
+// origin and the path of the second hailstone. It hits the third hailstone where that crosses
+// the plane, and by the same argument it hits the second where it crosses the plane through the
+// origin and the third. Two points on the rock's path at known times give its velocity and
+// starting position.
+fn throw_position(hailstones: &[(Point3, Point3)]) -> Vec3<BigRational> {
+    let (p0, v0) = (exact(hailstones[0].0), exact(hailstones[0].1));
+    let (p1, v1) = (exact(hailstones[1].0), exact(hailstones[1].1));
+    let (p2, v2) = (exact(hailstones[2].0), exact(hailstones[2].1));
+    let (rp1, rv1) = (p1.clone() - p0.clone(), v1.clone() - v0.clone());
+    let (rp2, rv2) = (p2.clone() - p0, v2.clone() - v0);
+
+    // The time a relative path p + t * v crosses the plane through the origin with normal n.
+    let crossing = |p: &Vec3<BigRational>, v: &Vec3<BigRational>, n: &Vec3<BigRational>| -p.dot(n) / v.dot(n);
+    let t1 = crossing(&rp1, &rv1, &rp2.cross(&rv2));
+    let t2 = crossing(&rp2, &rv2, &rp1.cross(&rv1));
+
+    let hit1 = p1 + v1 * t1.clone();
+    let hit2 = p2 + v2 * t2.clone();
+    let velocity = (hit2 - hit1.clone()) / (t2 - t1.clone());
+    hit1 - velocity * t1
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Vec<(Point3, Point3)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(hailstones: &Self::Input) -> Answer {
+        count_crossings(hailstones, 200000000000000, 400000000000000).into()
+    }
+
+    fn part2(hailstones: &Self::Input) -> Answer {
+        let p = throw_position(hailstones);
+        let sum = p.x + p.y + p.z;
+        assert!(sum.is_integer());
+        sum.to_integer().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day24, "24", part2: ["example1"]);
+
+    use super::*;
+
+    #[test]
+    fn test_count_crossings() {
+        let hailstones = parse_input(include_str!("../../inputs/day24/example1.txt")).unwrap();
+        assert_eq!(count_crossings(&hailstones, 7, 27), 2);
+    }
+}
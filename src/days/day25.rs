@@ -1,8 +1,10 @@
-use aoc2023::utils::stdio_lines;
-use std::collections::{HashMap, HashSet, VecDeque};
+use crate::search::{bfs_path, explore_bfs};
+use crate::solver::{Answer, Solver};
+use crate::error::{Error, Result};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone)]
-struct Graph {
+pub struct Graph {
     edges: HashMap<String, HashMap<String, i64>>,
     vertices: HashSet<String>,
 }
@@ -24,10 +26,6 @@ impl Graph {
         self.edges.get_mut(&from).unwrap().insert(to, capacity);
     }
 
-    fn has_edge(&self, from: &str, to: &str) -> bool {
-        self.edges.get(from).unwrap().get(to).is_some()
-    }
-
     fn change_capacity(&mut self, from: &str, to: &str, diff: i64) {
         if let Some(c) = self.edges.get_mut(from).unwrap().get_mut(to) {
             *c += diff;
@@ -37,67 +35,37 @@ impl Graph {
     }
 }
 
-fn parse_input() -> Graph {
+fn parse_input(input: &str) -> Result<Graph> {
     let mut graph = Graph::new();
-    for line in stdio_lines() {
-        let s: Vec<&str> = line.split(": ").collect();
-        for dst in s[1].split_ascii_whitespace() {
-            graph.add_edge(String::from(s[0]), String::from(dst), 1);
-            graph.add_edge(String::from(dst), String::from(s[0]), 1);
+    for (i, line) in input.lines().enumerate() {
+        let (src, dsts) = line.split_once(": ")
+            .ok_or_else(|| Error::new("Expected a component followed by \": \"").at_line(i + 1))?;
+        for dst in dsts.split_ascii_whitespace() {
+            graph.add_edge(String::from(src), String::from(dst), 1);
+            graph.add_edge(String::from(dst), String::from(src), 1);
         }
     }
-    graph
+    Ok(graph)
+}
+
+// The neighbours that can still be reached with the remaining capacity.
+fn open_edges<'a>(graph: &'a Graph, node: &str) -> Vec<&'a str> {
+    graph.edges[node].iter()
+        .filter(|(_, &cap)| cap > 0)
+        .map(|(n, _)| n.as_str())
+        .collect()
 }
 
 fn find_path(graph: &Graph, from: &str, to: &str) -> Option<Vec<String>> {
-    let mut queue = VecDeque::new();
-    let mut visited = HashSet::new();
-    let mut comes_from= HashMap::new();
-
-    queue.push_back((from, 0usize));
-    visited.insert(from);
-    while !queue.is_empty() {
-        let (node, dist) = queue.pop_front().unwrap();
-        if *node == *to {
-            let mut path = Vec::new();
-            path.push(String::from(node));
-            loop {
-                let last = path.last().unwrap();
-                if *last == *from {
-                    path.reverse();
-                    return Some(path);
-                } else {
-                    path.push(String::from(*comes_from.get(last.as_str()).unwrap()));
-                }
-            }
-        }
-        for (n, cap) in &graph.edges[node] {
-            if !visited.contains(n.as_str()) && *cap > 0 {
-                visited.insert(n);
-                comes_from.insert(n.as_str(), node);
-                queue.push_back((n, dist + 1));
-            }
-        }
-    }
-    None
+    bfs_path(from, |&node| open_edges(graph, node), |&node| node == to)
+        .map(|(_, path)| path.into_iter().map(String::from).collect())
 }
 
 fn find_component(graph: &Graph, source: &str) -> HashSet<String> {
-    let mut queue = VecDeque::new();
-    let mut visited = HashSet::new();
-
-    queue.push_back(source);
-    visited.insert(String::from(source));
-    while !queue.is_empty() {
-        let node = queue.pop_front().unwrap();
-        for (n, cap) in &graph.edges[node] {
-            if !visited.contains(n) && *cap > 0 {
-                visited.insert(n.clone());
-                queue.push_back(n);
-            }
-        }
-    }
-    visited
+    explore_bfs(source, |&node| open_edges(graph, node), |_| false, false)
+        .distances().keys()
+        .map(|&node| String::from(node))
+        .collect()
 }
 
 fn min_cut_between(mut graph: Graph, source: &str, sink: &str) -> (i64, HashSet<String>) {
@@ -132,9 +100,27 @@ fn min_cut(graph: &Graph) -> (i64, HashSet<String>) {
     best
 }
 
-fn main() {
-    let graph = parse_input();
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(graph: &Self::Input) -> Answer {
+        let (_best, set) = min_cut(graph);
+        (set.len() * (graph.vertices.len() - set.len())).into()
+    }
+
+    fn part2(_graph: &Self::Input) -> Answer {
+        // There is no second puzzle on the last day.
+        "Merry Christmas!".into()
+    }
+}
 
-    let (_best, set) = min_cut(&graph);
-    println!("{}", set.len() * (graph.vertices.len() - set.len()));
+#[cfg(test)]
+mod tests {
+    crate::example_tests!(super::Day25, "25", part1: ["example1"]);
 }
@@ -0,0 +1,58 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::solver::Puzzle;
+
+pub fn puzzle(day: u32) -> Option<Puzzle> {
+    match day {
+        1 => Some(Puzzle::of::<day01::Day01>()),
+        2 => Some(Puzzle::of::<day02::Day02>()),
+        3 => Some(Puzzle::of::<day03::Day03>()),
+        4 => Some(Puzzle::of::<day04::Day04>()),
+        5 => Some(Puzzle::of::<day05::Day05>()),
+        6 => Some(Puzzle::of::<day06::Day06>()),
+        7 => Some(Puzzle::of::<day07::Day07>()),
+        8 => Some(Puzzle::of::<day08::Day08>()),
+        9 => Some(Puzzle::of::<day09::Day09>()),
+        10 => Some(Puzzle::of::<day10::Day10>()),
+        11 => Some(Puzzle::of::<day11::Day11>()),
+        12 => Some(Puzzle::of::<day12::Day12>()),
+        13 => Some(Puzzle::of::<day13::Day13>()),
+        14 => Some(Puzzle::of::<day14::Day14>()),
+        15 => Some(Puzzle::of::<day15::Day15>()),
+        16 => Some(Puzzle::of::<day16::Day16>()),
+        17 => Some(Puzzle::of::<day17::Day17>()),
+        18 => Some(Puzzle::of::<day18::Day18>()),
+        19 => Some(Puzzle::of::<day19::Day19>()),
+        20 => Some(Puzzle::of::<day20::Day20>()),
+        21 => Some(Puzzle::of::<day21::Day21>()),
+        22 => Some(Puzzle::of::<day22::Day22>()),
+        23 => Some(Puzzle::of::<day23::Day23>()),
+        24 => Some(Puzzle::of::<day24::Day24>()),
+        25 => Some(Puzzle::of::<day25::Day25>()),
+        _ => None,
+    }
+}
@@ -1,4 +1,6 @@
-use crate::grid::Point;
+use crate::error::{Error, Result};
+use crate::point::Point;
+use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
 pub enum Dir {
@@ -8,41 +10,192 @@ pub enum Dir {
     Left,
 }
 
-pub fn cw(dir: Dir) -> Dir {
-    use Dir::*;
-    match dir {
-        Up => Right,
-        Right => Down,
-        Down => Left,
-        Left => Up,
+impl Dir {
+    // In clockwise order, starting from Up.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub fn cw(self) -> Dir {
+        use Dir::*;
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn ccw(self) -> Dir {
+        use Dir::*;
+        match self {
+            Up => Left,
+            Right => Up,
+            Down => Right,
+            Left => Down,
+        }
+    }
+
+    pub fn opposite(self) -> Dir {
+        self.cw().cw()
+    }
+
+    // The (row, col) change of a step in this direction.
+    pub fn delta(self) -> (i32, i32) {
+        use Dir::*;
+        match self {
+            Up => (-1, 0),
+            Right => (0, 1),
+            Down => (1, 0),
+            Left => (0, -1),
+        }
+    }
+
+    // The arrow pointing in the direction, for drawing paths.
+    pub fn arrow(self) -> char {
+        use Dir::*;
+        match self {
+            Up => '^',
+            Right => '>',
+            Down => 'v',
+            Left => '<',
+        }
+    }
+
+    // Accepts U/R/D/L, N/E/S/W or an arrow.
+    pub fn from_char(ch: char) -> Result<Dir> {
+        use Dir::*;
+        match ch {
+            'U' | 'N' | '^' => Ok(Up),
+            'R' | 'E' | '>' => Ok(Right),
+            'D' | 'S' | 'v' => Ok(Down),
+            'L' | 'W' | '<' => Ok(Left),
+            _ => Err(Error::new(format!("Bad direction {:?}", ch))),
+        }
     }
 }
 
-pub fn ccw(dir: Dir) -> Dir {
-    use Dir::*;
-    match dir {
-        Up => Left,
-        Right => Up,
-        Down => Right,
-        Left => Down,
+impl FromStr for Dir {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Dir> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Dir::from_char(ch),
+            _ => Err(Error::new(format!("Bad direction {:?}", s))),
+        }
     }
 }
 
 pub fn step(p: Point, dir: Dir) -> Point {
-    use Dir::*;
-    match dir {
-        Up => Point{ row: p.row - 1, col: p.col },
-        Right => Point{ row: p.row, col: p.col + 1 },
-        Down => Point{ row: p.row + 1, col: p.col },
-        Left => Point{ row: p.row, col: p.col - 1 },
-    }
+    p + dir.delta().into()
 }
 
 pub fn cart_neighbours(p: &Point) -> Vec<Point> {
-    vec![
-        Point{ row: p.row - 1, col: p.col },
-        Point{ row: p.row + 1, col: p.col },
-        Point{ row: p.row, col: p.col - 1 },
-        Point{ row: p.row, col: p.col + 1 },
-    ]
+    Dir::ALL.iter().map(|&dir| step(*p, dir)).collect()
+}
+
+// The eight compass directions, including the diagonals.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    // In clockwise order, starting from N.
+    pub const ALL: [Dir8; 8] = [Dir8::N, Dir8::NE, Dir8::E, Dir8::SE, Dir8::S, Dir8::SW, Dir8::W, Dir8::NW];
+
+    fn index(self) -> usize {
+        Dir8::ALL.iter().position(|&d| d == self).unwrap()
+    }
+
+    // Turn 45 degrees clockwise.
+    pub fn cw(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 1) % 8]
+    }
+
+    // Turn 45 degrees counter-clockwise.
+    pub fn ccw(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    // The (row, col) change of a step in this direction.
+    pub fn delta(self) -> (i32, i32) {
+        use Dir8::*;
+        match self {
+            N => (-1, 0),
+            NE => (-1, 1),
+            E => (0, 1),
+            SE => (1, 1),
+            S => (1, 0),
+            SW => (1, -1),
+            W => (0, -1),
+            NW => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Dir8 {
+        match dir {
+            Dir::Up => Dir8::N,
+            Dir::Right => Dir8::E,
+            Dir::Down => Dir8::S,
+            Dir::Left => Dir8::W,
+        }
+    }
+}
+
+pub fn step8(p: Point, dir: Dir8) -> Point {
+    p + dir.delta().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.cw().ccw(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_ne!(dir.opposite(), dir);
+            assert_eq!(step(step(Point{ row: 0, col: 0 }, dir), dir.opposite()), Point{ row: 0, col: 0 });
+            assert_eq!(Dir::from_char(dir.arrow()), Ok(dir));
+        }
+        assert_eq!(Dir::Up.cw(), Dir::Right);
+        assert_eq!("N".parse(), Ok(Dir::Up));
+        assert_eq!("L".parse(), Ok(Dir::Left));
+        assert!("x".parse::<Dir>().is_err());
+        assert!("UD".parse::<Dir>().is_err());
+    }
+
+    #[test]
+    fn test_dir8() {
+        for dir in Dir8::ALL {
+            assert_eq!(dir.cw().ccw(), dir);
+            assert_eq!(dir.cw().cw().cw().cw(), dir.opposite());
+            let (dr, dc) = dir.delta();
+            let (or, oc) = dir.opposite().delta();
+            assert_eq!((dr + or, dc + oc), (0, 0));
+            assert_eq!(dir.is_diagonal(), dr != 0 && dc != 0);
+        }
+        for dir in Dir::ALL {
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+        assert_eq!(step8(Point{ row: 0, col: 0 }, Dir8::SW), Point{ row: 1, col: -1 });
+    }
 }
@@ -0,0 +1,68 @@
+use std::fmt;
+
+// An error from parsing puzzle input. Line and column numbers are 1-based and are filled in
+// by whoever knows them, typically the code iterating over the lines of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    msg: String,
+    line: Option<usize>,
+    col: Option<usize>,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new<S: Into<String>>(msg: S) -> Error {
+        Error{ msg: msg.into(), line: None, col: None }
+    }
+
+    pub fn message(&self) -> &str { &self.msg }
+    pub fn line(&self) -> Option<usize> { self.line }
+    pub fn col(&self) -> Option<usize> { self.col }
+
+    // Attach a line number, unless a more specific one is already known.
+    pub fn at_line(mut self, line: usize) -> Error {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn at_col(mut self, col: usize) -> Error {
+        self.col.get_or_insert(col);
+        self
+    }
+
+    // Shift the line number by the number of lines preceding the section it was reported in.
+    pub fn offset_lines(mut self, preceding: usize) -> Error {
+        if let Some(line) = self.line.as_mut() {
+            *line += preceding;
+        }
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.col) {
+            (Some(line), Some(col)) => write!(f, "line {}, column {}: {}", line, col, self.msg),
+            (Some(line), None) => write!(f, "line {}: {}", line, self.msg),
+            (None, Some(col)) => write!(f, "column {}: {}", col, self.msg),
+            (None, None) => write!(f, "{}", self.msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Error::new("bad").to_string(), "bad");
+        assert_eq!(Error::new("bad").at_line(3).to_string(), "line 3: bad");
+        assert_eq!(Error::new("bad").at_col(5).at_line(3).to_string(), "line 3, column 5: bad");
+        assert_eq!(Error::new("bad").at_line(3).at_line(4).to_string(), "line 3: bad");
+        assert_eq!(Error::new("bad").at_line(3).offset_lines(10).to_string(), "line 13: bad");
+    }
+}
@@ -0,0 +1,243 @@
+use crate::dir::Dir;
+use crate::error::{Error, Result};
+use crate::numeric::gcd;
+use crate::point::{Point, Point3};
+use num_rational::Ratio;
+use num_traits::{Num, Zero};
+use std::convert::TryFrom;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+// Areas and lattice point counts for simple polygons whose vertices lie on grid points. Areas are
+// given doubled, since a polygon with lattice vertices may have an area ending in one half.
+
+// Twice the area of a polygon by the shoelace formula. The vertices go around the polygon in
+// order, without repeating the first at the end, and may include points along straight edges.
+pub fn shoelace(vertices: &[Point]) -> i64 {
+    let mut sum = 0i64;
+    for (i, p) in vertices.iter().enumerate() {
+        let q = vertices[(i + 1) % vertices.len()];
+        sum += p.col as i64 * q.row as i64 - q.col as i64 * p.row as i64;
+    }
+    sum.abs()
+}
+
+// The number of lattice points on the edges of a polygon.
+pub fn boundary_points(vertices: &[Point]) -> i64 {
+    let mut count = 0;
+    for (i, &p) in vertices.iter().enumerate() {
+        let d = vertices[(i + 1) % vertices.len()] - p;
+        count += gcd(d.row.unsigned_abs() as usize, d.col.unsigned_abs() as usize) as i64;
+    }
+    count
+}
+
+// Pick's theorem, A = i + b/2 - 1, solved for the number of lattice points strictly inside a
+// polygon with the given doubled area and number of boundary points.
+pub fn interior_points(double_area: i64, boundary: i64) -> i64 {
+    (double_area - boundary + 2) / 2
+}
+
+// The corners visited by following the moves from the origin. The moves must end up back at the
+// origin, which is not repeated. Fails if they don't, or if a corner is too far out for a Point.
+pub fn trace_moves(moves: &[(Dir, i64)]) -> Result<Vec<Point>> {
+    let coord = |n: i64| i32::try_from(n).map_err(|_| Error::new(format!("Coordinate {} is out of range", n)));
+    let (mut row, mut col) = (0i64, 0i64);
+    let mut ret = Vec::new();
+    for &(dir, len) in moves {
+        ret.push(Point::new(coord(row)?, coord(col)?));
+        let (dr, dc) = dir.delta();
+        row += dr as i64 * len;
+        col += dc as i64 * len;
+    }
+    if (row, col) != (0, 0) {
+        return Err(Error::new(format!("The moves end at ({}, {}) instead of back at the start", row, col)));
+    }
+    Ok(ret)
+}
+
+// The number of lattice points inside or on a polygon.
+pub fn enclosed_points(vertices: &[Point]) -> i64 {
+    let boundary = boundary_points(vertices);
+    interior_points(shoelace(vertices), boundary) + boundary
+}
+
+// A vector in space over any number type. Over integers, everything but division is exact; with
+// BigRational, all of it is, and can't overflow.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Num + Clone> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3{ x, y, z }
+    }
+
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> Vec3<U> {
+        Vec3{ x: f(self.x), y: f(self.y), z: f(self.z) }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero() && self.z.is_zero()
+    }
+
+    pub fn dot(&self, other: &Vec3<T>) -> T {
+        self.x.clone() * other.x.clone() + self.y.clone() * other.y.clone() + self.z.clone() * other.z.clone()
+    }
+
+    pub fn cross(&self, other: &Vec3<T>) -> Vec3<T> {
+        Vec3{
+            x: self.y.clone() * other.z.clone() - self.z.clone() * other.y.clone(),
+            y: self.z.clone() * other.x.clone() - self.x.clone() * other.z.clone(),
+            z: self.x.clone() * other.y.clone() - self.y.clone() * other.x.clone(),
+        }
+    }
+
+    // The same vector with z = 0, for working in the xy plane.
+    pub fn xy(&self) -> Vec3<T> {
+        Vec3{ x: self.x.clone(), y: self.y.clone(), z: T::zero() }
+    }
+}
+
+impl From<Point3> for Vec3<i64> {
+    fn from(p: Point3) -> Vec3<i64> {
+        Vec3{ x: p.x, y: p.y, z: p.z }
+    }
+}
+
+impl<T: Num> Add for Vec3<T> {
+    type Output = Vec3<T>;
+    fn add(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3{ x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+    }
+}
+
+impl<T: Num> Sub for Vec3<T> {
+    type Output = Vec3<T>;
+    fn sub(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3{ x: self.x - other.x, y: self.y - other.y, z: self.z - other.z }
+    }
+}
+
+impl<T: Num + Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+    fn neg(self) -> Vec3<T> {
+        Vec3{ x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl<T: Num + Clone> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+    fn mul(self, k: T) -> Vec3<T> {
+        Vec3{ x: self.x * k.clone(), y: self.y * k.clone(), z: self.z * k }
+    }
+}
+
+impl<T: Num + Clone> Div<T> for Vec3<T> {
+    type Output = Vec3<T>;
+    fn div(self, k: T) -> Vec3<T> {
+        Vec3{ x: self.x / k.clone(), y: self.y / k.clone(), z: self.z / k }
+    }
+}
+
+// Where the lines p0 + t0 * v0 and p1 + t1 * v1 cross, as the pair (t0, t1). None if they are
+// parallel or, in 3D, if they miss each other. The lines are given over fractions, since they
+// can cross between lattice points.
+pub fn intersect_lines<T>(p0: &Vec3<Ratio<T>>, v0: &Vec3<Ratio<T>>, p1: &Vec3<Ratio<T>>, v1: &Vec3<Ratio<T>>)
+    -> Option<(Ratio<T>, Ratio<T>)>
+    where Ratio<T>: Num + Clone
+{
+    let n = v0.cross(v1);
+    let d = p1.clone() - p0.clone();
+    if n.is_zero() || !d.dot(&n).is_zero() {
+        return None;
+    }
+    let nn = n.dot(&n);
+    Some((d.cross(v1).dot(&n) / nn.clone(), d.cross(v0).dot(&n) / nn))
+}
+
+// Like intersect_lines for the projections of the lines onto the xy plane.
+pub fn intersect_lines_xy<T>(p0: &Vec3<Ratio<T>>, v0: &Vec3<Ratio<T>>, p1: &Vec3<Ratio<T>>, v1: &Vec3<Ratio<T>>)
+    -> Option<(Ratio<T>, Ratio<T>)>
+    where Ratio<T>: Num + Clone
+{
+    intersect_lines(&p0.xy(), &v0.xy(), &p1.xy(), &v1.xy())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Dir::*;
+
+    #[test]
+    fn test_polygons() {
+        let square: Vec<Point> = vec![(0, 0), (0, 2), (2, 2), (2, 0)].into_iter().map(Point::from).collect();
+        assert_eq!(shoelace(&square), 8);
+        assert_eq!(boundary_points(&square), 8);
+        assert_eq!(interior_points(8, 8), 1);
+
+        let triangle: Vec<Point> = vec![(0, 0), (3, 3), (0, 3)].into_iter().map(Point::from).collect();
+        assert_eq!(shoelace(&triangle), 9);
+        assert_eq!(boundary_points(&triangle), 9);
+        assert_eq!(interior_points(9, 9), 1);
+    }
+
+    #[test]
+    fn test_vec3() {
+        let a = Vec3::new(1, 2, 3);
+        let b = Vec3::new(4, 5, 6);
+        assert_eq!(a.dot(&b), 32);
+        assert_eq!(a.cross(&b), Vec3::new(-3, 6, -3));
+        assert_eq!(a.cross(&b).dot(&a), 0);
+        assert_eq!(b.clone() - a.clone() * 2, Vec3::new(2, 1, 0));
+        assert_eq!(-(b / 2), Vec3::new(-2, -2, -3));
+        assert_eq!(Vec3::from(Point3::new(1, 2, 3)), a);
+    }
+
+    #[test]
+    fn test_intersect_lines() {
+        use num_rational::BigRational;
+        let q = |x: i64, y: i64, z: i64| Vec3::new(x, y, z).map(|n| BigRational::from_integer(n.into()));
+        let half = BigRational::new(1.into(), 2.into());
+
+        // Two lines crossing at (1, 1, 1).
+        let (t0, t1) = intersect_lines(&q(0, 0, 0), &q(2, 2, 2), &q(1, 0, 1), &q(0, 3, 0)).unwrap();
+        assert_eq!((t0, t1), (half, BigRational::new(1.into(), 3.into())));
+
+        // Skew lines only meet when projected onto the xy plane.
+        assert_eq!(intersect_lines(&q(0, 0, 0), &q(1, 0, 0), &q(0, 1, 1), &q(0, 1, 0)), None);
+        assert!(intersect_lines_xy(&q(0, 0, 0), &q(1, 0, 0), &q(0, 1, 1), &q(0, 1, 0)).is_some());
+
+        // Parallel lines.
+        assert_eq!(intersect_lines_xy(&q(0, 0, 0), &q(1, 1, 0), &q(0, 1, 0), &q(2, 2, 0)), None);
+
+        // Integer lines go in as fractions, so a crossing between lattice points isn't rounded.
+        let r = |x: i64, y: i64, z: i64| Vec3::new(x, y, z).map(Ratio::from_integer);
+        let (t0, t1) = intersect_lines_xy(&r(0, 0, 0), &r(1, 1, 0), &r(1, 0, 0), &r(0, 1, 0)).unwrap();
+        assert_eq!((t0, t1), (Ratio::new(1, 1), Ratio::new(1, 1)));
+        let (t0, t1) = intersect_lines_xy(&r(0, 0, 0), &r(2, 2, 0), &r(1, 0, 0), &r(0, 2, 0)).unwrap();
+        assert_eq!((t0, t1), (Ratio::new(1, 2), Ratio::new(1, 2)));
+    }
+
+    #[test]
+    fn test_moves() {
+        let moves = [(Right, 2), (Down, 2), (Left, 2), (Up, 2)];
+        let square = trace_moves(&moves).unwrap();
+        assert_eq!(square[2], Point::new(2, 2));
+        assert_eq!(enclosed_points(&square), 9);
+
+        // An L shape, traced counter-clockwise.
+        let moves = [(Down, 2), (Right, 1), (Up, 1), (Right, 1), (Up, 1), (Left, 2)];
+        assert_eq!(enclosed_points(&trace_moves(&moves).unwrap()), 8);
+
+        // Too long to fit, rather than wrapping around.
+        let moves = [(Right, 1 << 32), (Down, 1), (Left, 1 << 32), (Up, 1)];
+        assert!(trace_moves(&moves).is_err());
+
+        // Doesn't close the loop.
+        let err = trace_moves(&[(Right, 2), (Down, 2)]).unwrap_err();
+        assert_eq!(err.to_string(), "The moves end at (2, 2) instead of back at the start");
+    }
+}
@@ -0,0 +1,276 @@
+use crate::dir::{step, Dir};
+use crate::grid::{Grid, Point};
+use std::collections::HashMap;
+
+// A directed graph with weighted edges. Nodes are numbered from 0 in the order they were added,
+// and each carries a value, such as its position in a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Graph<N> {
+        Graph{ nodes: Vec::new(), edges: Vec::new() }
+    }
+}
+
+impl<N> Graph<N> {
+    pub fn new() -> Graph<N> {
+        Graph::default()
+    }
+
+    pub fn add_node(&mut self, node: N) -> usize {
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: usize) {
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn len(&self) -> usize { self.nodes.len() }
+    pub fn is_empty(&self) -> bool { self.nodes.is_empty() }
+    pub fn node(&self, i: usize) -> &N { &self.nodes[i] }
+    pub fn nodes(&self) -> &[N] { &self.nodes }
+
+    // The (target, weight) pairs of the edges leaving a node.
+    pub fn edges(&self, i: usize) -> &[(usize, usize)] { &self.edges[i] }
+
+    pub fn index_of(&self, node: &N) -> Option<usize> where N: PartialEq {
+        self.nodes.iter().position(|n| n == node)
+    }
+
+    // The same graph with every edge also going the other way.
+    pub fn undirected(&self) -> Graph<N> where N: Clone {
+        let mut ret = self.clone();
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, weight) in edges {
+                if !ret.edges[to].contains(&(from, weight)) {
+                    ret.add_edge(to, from, weight);
+                }
+            }
+        }
+        ret
+    }
+}
+
+// Contract the corridors of a maze into weighted edges. The nodes are the passable cells that
+// don't have exactly two passable neighbours, i.e. junctions and dead ends, along with any cells
+// listed in `keep`, such as the start and the goal. Each corridor between two nodes becomes an
+// edge in both directions, weighted by its number of steps.
+pub fn compress_grid<T, P>(grid: &Grid<T>, passable: P, keep: &[Point]) -> Graph<Point>
+    where P: Fn(&T) -> bool
+{
+    compress_grid_directed(grid, passable, |_, _| true, keep)
+}
+
+// Like compress_grid, where a corridor only gives an edge in a direction if `can_move` allows
+// every step along it, such as steps off a one-way slope.
+pub fn compress_grid_directed<T, P, D>(grid: &Grid<T>, passable: P, can_move: D, keep: &[Point]) -> Graph<Point>
+    where P: Fn(&T) -> bool, D: Fn(Point, Dir) -> bool
+{
+    let open = |p: &Point| grid.containsp(p) && passable(grid.getp(p));
+    let exits = |p: Point| Dir::ALL.iter().copied().filter(move |&dir| open(&step(p, dir)));
+
+    let mut graph = Graph::new();
+    let mut index = HashMap::new();
+    for (p, val) in grid.iter_points() {
+        if passable(val) && (exits(p).count() != 2 || keep.contains(&p)) {
+            index.insert(p, graph.add_node(p));
+        }
+    }
+
+    for from in 0..graph.len() {
+        let start = *graph.node(from);
+        'corridors: for first in exits(start) {
+            let (mut pos, mut dir, mut len) = (start, first, 0);
+            loop {
+                if !can_move(pos, dir) {
+                    continue 'corridors;
+                }
+                pos = step(pos, dir);
+                len += 1;
+                if let Some(&to) = index.get(&pos) {
+                    graph.add_edge(from, to, len);
+                    continue 'corridors;
+                }
+                // Not a node, so there is exactly one way on that doesn't go back.
+                dir = exits(pos).find(|&d| d != dir.opposite()).unwrap();
+            }
+        }
+    }
+    graph
+}
+
+// The dominator tree of a directed graph given by its successor lists. Node a dominates node b if
+// every path from the root to b goes through a. Every node dominates itself, and the immediate
+// dominator of a node is its closest dominator other than itself. Uses the iterative algorithm of
+// Cooper, Harvey and Kennedy.
+pub struct DominatorTree {
+    root: usize,
+    idom: Vec<Option<usize>>,
+    // The nodes reachable from the root, in reverse postorder.
+    order: Vec<usize>,
+}
+
+impl DominatorTree {
+    pub fn new(successors: &[Vec<usize>], root: usize) -> DominatorTree {
+        let n = successors.len();
+        let mut postorder = Vec::new();
+        let mut seen = vec![false; n];
+        let mut stack = vec![(root, 0)];
+        seen[root] = true;
+        while let Some(&mut (node, ref mut next)) = stack.last_mut() {
+            if let Some(&child) = successors[node].get(*next) {
+                *next += 1;
+                if !seen[child] {
+                    seen[child] = true;
+                    stack.push((child, 0));
+                }
+            } else {
+                postorder.push(node);
+                stack.pop();
+            }
+        }
+
+        let mut rank = vec![0; n];
+        for (i, &node) in postorder.iter().enumerate() {
+            rank[node] = i;
+        }
+        let mut predecessors = vec![Vec::new(); n];
+        for &node in &postorder {
+            for &child in &successors[node] {
+                predecessors[child].push(node);
+            }
+        }
+
+        let mut idom = vec![None; n];
+        idom[root] = Some(root);
+        let order: Vec<usize> = postorder.into_iter().rev().collect();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &node in &order[1..] {
+                let mut new_idom = None;
+                for &pred in &predecessors[node] {
+                    if idom[pred].is_none() {
+                        continue;
+                    }
+                    new_idom = Some(match new_idom {
+                        None => pred,
+                        Some(other) => {
+                            let (mut a, mut b) = (pred, other);
+                            while a != b {
+                                while rank[a] < rank[b] { a = idom[a].unwrap(); }
+                                while rank[b] < rank[a] { b = idom[b].unwrap(); }
+                            }
+                            a
+                        }
+                    });
+                }
+                if new_idom != idom[node] {
+                    idom[node] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+        idom[root] = None;
+        DominatorTree{ root, idom, order }
+    }
+
+    // The immediate dominator, or None for the root and for nodes the root can't reach.
+    pub fn idom(&self, node: usize) -> Option<usize> {
+        self.idom[node]
+    }
+
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        if b != self.root && self.idom[b].is_none() {
+            return false;
+        }
+        let mut node = Some(b);
+        while let Some(n) = node {
+            if n == a {
+                return true;
+            }
+            node = self.idom[n];
+        }
+        false
+    }
+
+    // The number of nodes each node dominates, including itself, or 0 if the root can't reach it.
+    pub fn subtree_sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.idom.len()];
+        for &node in self.order.iter().rev() {
+            sizes[node] += 1;
+            if let Some(parent) = self.idom[node] {
+                sizes[parent] += sizes[node];
+            }
+        }
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graph() {
+        let mut g = Graph::new();
+        let a = g.add_node('a');
+        let b = g.add_node('b');
+        g.add_edge(a, b, 3);
+        assert_eq!(g.len(), 2);
+        assert_eq!(g.index_of(&'b'), Some(b));
+        assert_eq!(g.edges(b), &[]);
+        let u = g.undirected();
+        assert_eq!(u.edges(b), &[(a, 3)]);
+        assert_eq!(u.undirected(), u);
+    }
+
+    #[test]
+    fn test_dominators() {
+        // 0 -> 1 -> 2 -> 4 -> 5, with a detour 1 -> 3 -> 4, a loop back 5 -> 1 and an
+        // unreachable 6 -> 5.
+        let successors = vec![vec![1], vec![2, 3], vec![4], vec![4], vec![5], vec![1], vec![5]];
+        let tree = DominatorTree::new(&successors, 0);
+        let idoms: Vec<Option<usize>> = (0..7).map(|n| tree.idom(n)).collect();
+        assert_eq!(idoms, vec![None, Some(0), Some(1), Some(1), Some(1), Some(4), None]);
+        assert!(tree.dominates(1, 5));
+        assert!(tree.dominates(5, 5));
+        assert!(!tree.dominates(2, 4));
+        assert!(!tree.dominates(0, 6));
+        assert_eq!(tree.subtree_sizes(), vec![6, 5, 1, 1, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_compress() {
+        let grid: Grid<char> = "\
+#S###
+#...#
+#.#.#
+#...#
+###>E".parse().unwrap();
+        let start = Point{ row: 0, col: 1 };
+        let end = Point{ row: 4, col: 4 };
+        let g = compress_grid(&grid, |&c| c != '#', &[start, end]);
+        // The start, the end and the two junctions of the loop.
+        assert_eq!(g.len(), 4);
+        let (s, e) = (g.index_of(&start).unwrap(), g.index_of(&end).unwrap());
+        let j1 = g.index_of(&Point{ row: 1, col: 1 }).unwrap();
+        let j2 = g.index_of(&Point{ row: 3, col: 3 }).unwrap();
+        assert_eq!(g.edges(s), &[(j1, 1)]);
+        let mut loop_edges = g.edges(j1).to_vec();
+        loop_edges.sort();
+        assert_eq!(loop_edges, vec![(s, 1), (j2, 4), (j2, 4)]);
+        assert_eq!(g.edges(e), &[(j2, 2)]);
+
+        // The slope only lets you go right.
+        let g = compress_grid_directed(&grid, |&c| c != '#', |p, dir| *grid.getp(&p) != '>' || dir == Dir::Right, &[start, end]);
+        assert_eq!(g.edges(j2).iter().filter(|&&(to, _)| to == e).count(), 1);
+        assert!(g.edges(e).is_empty());
+    }
+}
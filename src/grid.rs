@@ -1,10 +1,12 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point {
-    pub row: i32,
-    pub col: i32,
-}
+use crate::dir::{step, step8, Dir, Dir8};
+use crate::error::{Error, Result};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+pub use crate::point::Point;
 
-#[derive(Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     width: i32,
     data: Vec<T>,
@@ -12,26 +14,63 @@ pub struct Grid<T> {
 
 pub struct GridBuilder<T> {
     width: i32,
+    rows: i32,
     data: Vec<T>,
 }
 
 impl<T> Grid<T> {
-    pub fn width(&self) -> i32 { return self.width; }
-    pub fn height(&self) -> i32 { return (self.data.len() as i32) / self.width(); }
+    pub fn width(&self) -> i32 { self.width }
+    pub fn height(&self) -> i32 { (self.data.len() as i32) / self.width() }
     fn index(&self, row: i32, col: i32) -> usize {
-        return (row * self.width() + col) as usize;
+        (row * self.width() + col) as usize
     }
     pub fn get(&self, row: i32, col: i32) -> &T {
-        return &self.data[self.index(row, col)];
+        &self.data[self.index(row, col)]
     }
     pub fn mutget(&mut self, row: i32, col: i32) -> &mut T {
         let ix = self.index(row, col);
-        return &mut self.data[ix];
+        &mut self.data[ix]
+    }
+    pub fn getp(&self, p: &Point) -> &T { self.get(p.row, p.col) }
+    pub fn mutgetp(&mut self, p: &Point) -> &mut T { self.mutget(p.row, p.col) }
+    pub fn contains(&self, row: i32, col: i32) -> bool { row >= 0 && row < self.height() && col >= 0 && col < self.width() }
+    pub fn containsp(&self, p: &Point) -> bool { self.contains(p.row, p.col) }
+
+    fn point(&self, index: usize) -> Point {
+        Point{ row: index as i32 / self.width, col: index as i32 % self.width }
+    }
+
+    // All cells in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> { self.data.iter() }
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> { self.data.iter_mut() }
+
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.data.iter().enumerate().map(move |(i, val)| (self.point(i), val))
+    }
+
+    pub fn row(&self, row: i32) -> std::slice::Iter<'_, T> {
+        let start = self.index(row, 0);
+        self.data[start..start + self.width as usize].iter()
+    }
+
+    pub fn col(&self, col: i32) -> impl Iterator<Item = &T> + '_ {
+        assert!(col >= 0 && col < self.width);
+        self.data.iter().skip(col as usize).step_by(self.width as usize)
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> { self.data.chunks(self.width as usize) }
+
+    // The orthogonal neighbours of p that lie inside the grid.
+    pub fn neighbours4(&self, p: &Point) -> impl Iterator<Item = Point> + '_ {
+        let p = *p;
+        Dir::ALL.iter().map(move |&dir| step(p, dir)).filter(move |n| self.containsp(n))
+    }
+
+    // The orthogonal and diagonal neighbours of p that lie inside the grid.
+    pub fn neighbours8(&self, p: &Point) -> impl Iterator<Item = Point> + '_ {
+        let p = *p;
+        Dir8::ALL.iter().map(move |&dir| step8(p, dir)).filter(move |n| self.containsp(n))
     }
-    pub fn getp(&self, p: &Point) -> &T { return self.get(p.row, p.col); }
-    pub fn mutgetp(&mut self, p: &Point) -> &mut T { return self.mutget(p.row, p.col); }
-    pub fn contains(&self, row: i32, col: i32) -> bool { return row >= 0 && row < self.height() && col >= 0 && col < self.width(); }
-    pub fn containsp(&self, p: &Point) -> bool { return self.contains(p.row, p.col); }
 }
 
 impl<T: Clone> Grid<T> {
@@ -40,26 +79,424 @@ impl<T: Clone> Grid<T> {
         grid.data.resize((width * height) as usize, item);
         grid
     }
+
+    // Build a grid of the given size where each cell is copied from a point in this one.
+    fn remap<F>(&self, height: i32, width: i32, f: F) -> Grid<T>
+        where F: Fn(i32, i32) -> Point
+    {
+        Grid::from_fn(height, width, |p| self.getp(&f(p.row, p.col)).clone())
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.width(), self.height(), |r, c| Point{ row: c, col: r })
+    }
+
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let h = self.height();
+        self.remap(self.width(), h, |r, c| Point{ row: h - 1 - c, col: r })
+    }
+
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let w = self.width();
+        self.remap(w, self.height(), |r, c| Point{ row: c, col: w - 1 - r })
+    }
+
+    // Mirror left to right.
+    pub fn flip_h(&self) -> Grid<T> {
+        let w = self.width();
+        self.remap(self.height(), w, |r, c| Point{ row: r, col: w - 1 - c })
+    }
+
+    // Mirror top to bottom.
+    pub fn flip_v(&self) -> Grid<T> {
+        let h = self.height();
+        self.remap(h, self.width(), |r, c| Point{ row: h - 1 - r, col: c })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F>(height: i32, width: i32, mut f: F) -> Grid<T>
+        where F: FnMut(Point) -> T
+    {
+        let mut data = Vec::with_capacity((width * height) as usize);
+        for row in 0..height {
+            for col in 0..width {
+                data.push(f(Point{ row, col }));
+            }
+        }
+        Grid{ width, data }
+    }
+
+    // A view of the height x width rectangle with its top left corner at origin.
+    pub fn window(&self, origin: Point, height: i32, width: i32) -> GridWindow<'_, T> {
+        assert!(height >= 0 && width >= 0);
+        assert!(height == 0 || width == 0 ||
+                (self.containsp(&origin) && self.contains(origin.row + height - 1, origin.col + width - 1)));
+        GridWindow{ grid: self, origin, height, width }
+    }
+}
+
+// A rectangular part of a grid, borrowed rather than copied. Coordinates are relative to the
+// window's top left corner.
+#[derive(Clone, Copy)]
+pub struct GridWindow<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    height: i32,
+    width: i32,
+}
+
+impl<'a, T> GridWindow<'a, T> {
+    pub fn width(&self) -> i32 { self.width }
+    pub fn height(&self) -> i32 { self.height }
+    pub fn get(&self, row: i32, col: i32) -> &'a T {
+        assert!(self.contains(row, col));
+        self.grid.get(self.origin.row + row, self.origin.col + col)
+    }
+    pub fn getp(&self, p: &Point) -> &'a T { self.get(p.row, p.col) }
+    pub fn contains(&self, row: i32, col: i32) -> bool { row >= 0 && row < self.height && col >= 0 && col < self.width }
+    pub fn containsp(&self, p: &Point) -> bool { self.contains(p.row, p.col) }
+
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        (0..self.height).flat_map(move |row| (0..self.width).map(move |col| (Point{ row, col }, self.get(row, col))))
+    }
+}
+
+impl<'a, T: Clone> GridWindow<'a, T> {
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| self.getp(&p).clone())
+    }
+}
+
+// Read access to cells by point, for code that shouldn't care whether the grid is bounded,
+// wraps around, or is stored sparsely. Points with no cell give None.
+pub trait GridView<T> {
+    fn at(&self, p: &Point) -> Option<&T>;
+}
+
+impl<T> GridView<T> for Grid<T> {
+    fn at(&self, p: &Point) -> Option<&T> {
+        if self.containsp(p) { Some(self.getp(p)) } else { None }
+    }
+}
+
+impl<'a, T> GridView<T> for GridWindow<'a, T> {
+    fn at(&self, p: &Point) -> Option<&T> {
+        if self.containsp(p) { Some(self.getp(p)) } else { None }
+    }
+}
+
+// A grid repeated infinitely in every direction.
+pub struct WrappingGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> GridView<T> for WrappingGrid<'a, T> {
+    fn at(&self, p: &Point) -> Option<&T> {
+        Some(self.grid.get(p.row.rem_euclid(self.grid.height()), p.col.rem_euclid(self.grid.width())))
+    }
+}
+
+// A grid surrounded by an infinite border of a default value.
+pub struct PaddedGrid<'a, T> {
+    grid: &'a Grid<T>,
+    default: T,
+}
+
+impl<'a, T> GridView<T> for PaddedGrid<'a, T> {
+    fn at(&self, p: &Point) -> Option<&T> {
+        Some(self.grid.at(p).unwrap_or(&self.default))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn wrapping(&self) -> WrappingGrid<'_, T> {
+        WrappingGrid{ grid: self }
+    }
+
+    pub fn padded(&self, default: T) -> PaddedGrid<'_, T> {
+        PaddedGrid{ grid: self, default }
+    }
+}
+
+// An unbounded grid that only stores the cells that have been set; every other cell has the
+// default value.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid{ cells: HashMap::new(), default }
+    }
+
+    pub fn get(&self, p: &Point) -> &T { self.cells.get(p).unwrap_or(&self.default) }
+    pub fn set(&mut self, p: Point, val: T) { self.cells.insert(p, val); }
+    pub fn len(&self) -> usize { self.cells.len() }
+    pub fn is_empty(&self) -> bool { self.cells.is_empty() }
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells.iter().map(|(p, val)| (*p, val))
+    }
+
+    // The smallest (top left, bottom right) rectangle containing every cell that has been set.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let rows = || self.cells.keys().map(|p| p.row);
+        let cols = || self.cells.keys().map(|p| p.col);
+        Some((Point{ row: rows().min()?, col: cols().min()? }, Point{ row: rows().max()?, col: cols().max()? }))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    pub fn get_mut(&mut self, p: Point) -> &mut T {
+        let default = &self.default;
+        self.cells.entry(p).or_insert_with(|| default.clone())
+    }
+}
+
+impl<T> GridView<T> for SparseGrid<T> {
+    fn at(&self, p: &Point) -> Option<&T> {
+        Some(self.get(p))
+    }
+}
+
+impl<T> Grid<T> {
+    // Parse a grid with one row per line, mapping each character to a cell. Trailing blank lines
+    // are ignored; errors from the mapping are tagged with the character's line and column.
+    pub fn parse<F>(input: &str, f: F) -> Result<Grid<T>>
+        where F: FnMut(char) -> Result<T>
+    {
+        let lines: Vec<&str> = input.lines().collect();
+        Grid::from_lines_with(&lines, f)
+    }
+
+    pub fn from_lines_with<S, F>(lines: &[S], mut f: F) -> Result<Grid<T>>
+        where S: AsRef<str>, F: FnMut(char) -> Result<T>
+    {
+        let mut lines: Vec<&str> = lines.iter().map(|l| l.as_ref()).collect();
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+        if lines.is_empty() {
+            return Err(Error::new("Empty grid"));
+        }
+
+        let mut builder = GridBuilder::new();
+        for (row, line) in lines.iter().enumerate() {
+            for (col, ch) in line.chars().enumerate() {
+                builder.add(f(ch).map_err(|e| e.at_line(row + 1).at_col(col + 1))?);
+            }
+            builder.eol()?;
+        }
+        Ok(builder.finish())
+    }
+}
+
+impl Grid<char> {
+    pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Result<Grid<char>> {
+        Grid::from_lines_with(lines, Ok)
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Grid<char>> {
+        Grid::parse(s, Ok)
+    }
+}
+
+impl<T> Grid<T> {
+    // Draw each cell as a character, e.g. to print a grid of something other than chars.
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> Grid<char> {
+        Grid{ width: self.width, data: self.data.iter().map(f).collect() }
+    }
+}
+
+impl Grid<char> {
+    // A copy with the given points marked. Points outside the grid are ignored.
+    pub fn with_points<'a, I>(&self, points: I, mark: char) -> Grid<char>
+        where I: IntoIterator<Item = &'a Point>
+    {
+        let mut ret = self.clone();
+        for p in points {
+            if ret.containsp(p) {
+                *ret.mutgetp(p) = mark;
+            }
+        }
+        ret
+    }
+
+    // A copy with an arrow drawn at each step of the path, pointing the way it went.
+    pub fn with_path(&self, path: &[(Point, Dir)]) -> Grid<char> {
+        let mut ret = self.clone();
+        for (p, dir) in path {
+            if ret.containsp(p) {
+                *ret.mutgetp(p) = dir.arrow();
+            }
+        }
+        ret
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Default for GridBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> GridBuilder<T> {
     pub fn new() -> GridBuilder<T> {
-        return GridBuilder{ width: -1, data: Vec::new()};
+        GridBuilder{ width: -1, rows: 0, data: Vec::new()}
     }
 
     pub fn add(&mut self, val: T) {
         self.data.push(val);
     }
 
-    pub fn eol(&mut self) {
+    pub fn eol(&mut self) -> Result<()> {
+        let len = self.data.len() as i32 - self.rows * self.width.max(0);
         if self.width < 0 {
-            self.width = self.data.len() as i32;
-        } else if self.width != 0 && (self.data.len() as i32) % self.width != 0 {
-            panic!("Mismatched line lengths in grid");
+            self.width = len;
+        } else if len != self.width {
+            return Err(Error::new(format!("Mismatched line lengths in grid: expected {}, got {}", self.width, len))
+                .at_line(self.rows as usize + 1));
         }
+        self.rows += 1;
+        Ok(())
     }
 
     pub fn finish(self) -> Grid<T> {
-        return Grid {width: self.width, data: self.data};
+        Grid {width: self.width, data: self.data}
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_mismatched_lines() {
+        let mut builder = GridBuilder::new();
+        for ch in "abc".chars() {
+            builder.add(ch);
+        }
+        assert_eq!(builder.eol(), Ok(()));
+        for ch in "de".chars() {
+            builder.add(ch);
+        }
+        let err = builder.eol().unwrap_err();
+        assert_eq!(err.to_string(), "line 2: Mismatched line lengths in grid: expected 3, got 2");
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<char> = "ab\ncd\n\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(*grid.get(1, 0), 'c');
+
+        let digits = Grid::parse("12\n34", |ch| ch.to_digit(10).ok_or_else(|| Error::new("Not a digit"))).unwrap();
+        assert_eq!(*digits.get(1, 1), 4);
+
+        let err = Grid::parse("12\n3x", |ch| ch.to_digit(10).ok_or_else(|| Error::new("Not a digit"))).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: Not a digit");
+        assert_eq!(Grid::from_lines(&["abc", "de"]).unwrap_err().line(), Some(2));
+        assert!("".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        assert_eq!(grid.iter().collect::<String>(), "abcdef");
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(grid.col(2).collect::<String>(), "cf");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.iter_points().find(|(_, &ch)| ch == 'e').map(|(p, _)| p), Some(Point{ row: 1, col: 1 }));
+
+        let mut upper = grid.clone();
+        upper.iter_mut().for_each(|ch| *ch = ch.to_ascii_uppercase());
+        assert_eq!(upper.row(0).collect::<String>(), "ABC");
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        let rows = |g: Grid<char>| g.rows().map(|r| r.iter().collect::<String>()).collect::<Vec<_>>();
+        assert_eq!(rows(grid.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(rows(grid.rotate_cw()), ["da", "eb", "fc"]);
+        assert_eq!(rows(grid.rotate_ccw()), ["cf", "be", "ad"]);
+        assert_eq!(rows(grid.flip_h()), ["cba", "fed"]);
+        assert_eq!(rows(grid.flip_v()), ["def", "abc"]);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn test_window() {
+        let grid: Grid<char> = "abcd\nefgh\nijkl".parse().unwrap();
+        let window = grid.window(Point{ row: 1, col: 1 }, 2, 2);
+        assert_eq!(*window.get(0, 0), 'f');
+        assert!(!window.contains(2, 0));
+        assert_eq!(window.iter_points().map(|(_, ch)| *ch).collect::<String>(), "fgjk");
+        assert_eq!(window.to_grid(), "fg\njk".parse().unwrap());
+    }
+
+    // Count the cells equal to val among the points, via any view.
+    fn count<V: GridView<char>>(view: &V, points: &[Point], val: char) -> usize {
+        points.iter().filter(|p| view.at(p) == Some(&val)).count()
+    }
+
+    #[test]
+    fn test_views() {
+        let grid: Grid<char> = "#.\n..".parse().unwrap();
+        let points = [Point{ row: 0, col: 0 }, Point{ row: -2, col: 2 }, Point{ row: 5, col: -3 }];
+        assert_eq!(grid.at(&points[1]), None);
+        assert_eq!(count(&grid, &points, '#'), 1);
+        assert_eq!(count(&grid.wrapping(), &points, '#'), 2);
+        assert_eq!(count(&grid.padded('#'), &points, '#'), 3);
+
+        let mut sparse = SparseGrid::new('.');
+        sparse.set(Point{ row: -2, col: 2 }, '#');
+        *sparse.get_mut(Point{ row: 5, col: -3 }) = '#';
+        assert_eq!(count(&sparse, &points, '#'), 2);
+        assert_eq!(sparse.bounds(), Some((Point{ row: -2, col: -3 }, Point{ row: 5, col: 2 })));
+        assert_eq!(SparseGrid::new(0).bounds(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let text = "#..\n.#.";
+        let grid: Grid<char> = text.parse().unwrap();
+        assert_eq!(grid.to_string(), text);
+
+        let numbers = Grid::from_fn(2, 3, |p| p.row * 3 + p.col);
+        assert_eq!(numbers.render(|n| if n % 2 == 0 { 'e' } else { 'o' }).to_string(), "eoe\noeo");
+
+        let marked = grid.with_points(&[Point{ row: 0, col: 2 }, Point{ row: 9, col: 9 }], 'O');
+        assert_eq!(marked.to_string(), "#.O\n.#.");
+        let path = [(Point{ row: 1, col: 0 }, Dir::Up), (Point{ row: 0, col: 1 }, Dir::Right)];
+        assert_eq!(grid.with_path(&path).to_string(), "#>.\n^#.");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::from_dim(3, 3, 0);
+        assert_eq!(grid.neighbours4(&Point{ row: 1, col: 1 }).count(), 4);
+        assert_eq!(grid.neighbours8(&Point{ row: 1, col: 1 }).count(), 8);
+        assert_eq!(grid.neighbours4(&Point{ row: 0, col: 0 }).collect::<Vec<_>>(),
+                   vec![Point{ row: 0, col: 1 }, Point{ row: 1, col: 0 }]);
+        assert_eq!(grid.neighbours8(&Point{ row: 2, col: 0 }).count(), 3);
+    }
+}
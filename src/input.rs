@@ -0,0 +1,94 @@
+use crate::error::{Error, Result};
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// Puzzle inputs live in <dir>/dayNN/<name>.txt, where the name is "real" for the personal puzzle
+// input and "example1", "example2", ... for the examples from the puzzle text.
+pub const INPUT_DIR: &str = "inputs";
+
+pub fn input_path<P: AsRef<Path>>(dir: P, day: u32, name: &str) -> PathBuf {
+    dir.as_ref().join(format!("day{:02}", day)).join(format!("{}.txt", name))
+}
+
+// Where to read an input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    // An input by name from the given inputs directory.
+    pub fn named<P: AsRef<Path>>(dir: P, day: u32, name: &str) -> Source {
+        Source::File(input_path(dir, day, name))
+    }
+
+    // The input given by path, or else by name from the inputs directory, or else stdin.
+    pub fn resolve(path: Option<&str>, dir: &str, day: u32, name: Option<&str>) -> Source {
+        match (path, name) {
+            (Some(path), _) => Source::File(PathBuf::from(path)),
+            (None, Some(name)) => Source::named(dir, day, name),
+            (None, None) => Source::Stdin,
+        }
+    }
+
+    pub fn read_to_string(&self) -> Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)
+                    .map_err(|e| Error::new(format!("Failed to read stdin: {}", e)))?;
+                Ok(input)
+            },
+            Source::File(path) => fs::read_to_string(path)
+                .map_err(|e| Error::new(format!("Failed to read {}: {}", path.display(), e))),
+        }
+    }
+
+    pub fn read_lines(&self) -> Result<Vec<String>> {
+        Ok(self.read_to_string()?.lines().map(String::from).collect())
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+// The named input for a day from the default inputs directory.
+pub fn load(day: u32, name: &str) -> Result<String> {
+    Source::named(INPUT_DIR, day, name).read_to_string()
+}
+
+pub fn load_lines(day: u32, name: &str) -> Result<Vec<String>> {
+    Source::named(INPUT_DIR, day, name).read_lines()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(input_path("inputs", 7, "example2"), PathBuf::from("inputs/day07/example2.txt"));
+        assert_eq!(Source::resolve(Some("x.txt"), "inputs", 1, Some("real")), Source::File(PathBuf::from("x.txt")));
+        assert_eq!(Source::resolve(None, "dir", 1, Some("real")), Source::File(PathBuf::from("dir/day01/real.txt")));
+        assert_eq!(Source::resolve(None, "inputs", 1, None), Source::Stdin);
+        assert_eq!(Source::Stdin.to_string(), "stdin");
+    }
+
+    #[test]
+    fn test_load() {
+        let lines = load_lines(1, "example1").unwrap();
+        assert_eq!(lines.first().map(|s| s.as_str()), Some("1abc2"));
+        assert_eq!(load(1, "example1").unwrap().lines().count(), lines.len());
+        let err = load(1, "missing").unwrap_err();
+        assert!(err.to_string().starts_with("Failed to read inputs/day01/missing.txt"));
+    }
+}
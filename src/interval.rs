@@ -0,0 +1,338 @@
+use num_traits::PrimInt;
+use std::iter::FromIterator;
+
+// A half-open range of integers, start <= x < end. An interval with end <= start is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval{ start, end }
+    }
+
+    // The interval lo <= x <= hi.
+    pub fn inclusive(lo: T, hi: T) -> Interval<T> {
+        Interval{ start: lo, end: hi + T::one() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() { T::zero() } else { self.end - self.start }
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    pub fn intersect(&self, other: &Interval<T>) -> Interval<T> {
+        Interval{ start: self.start.max(other.start), end: self.end.min(other.end) }
+    }
+
+    // The parts below x and from x on. Either may be empty.
+    pub fn split_at(&self, x: T) -> (Interval<T>, Interval<T>) {
+        let mid = x.max(self.start).min(self.end);
+        (Interval{ start: self.start, end: mid }, Interval{ start: mid, end: self.end })
+    }
+
+    pub fn shift(&self, offset: T) -> Interval<T> {
+        Interval{ start: self.start + offset, end: self.end + offset }
+    }
+}
+
+// A set of integers stored as sorted, disjoint, non-empty intervals. Touching intervals are
+// merged, so two sets with the same members always compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet{ intervals: Vec::new() }
+    }
+
+    fn normalized(mut intervals: Vec<Interval<T>>) -> IntervalSet<T> {
+        intervals.retain(|iv| !iv.is_empty());
+        intervals.sort_by_key(|iv| iv.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for iv in intervals {
+            match merged.last_mut() {
+                Some(last) if iv.start <= last.end => last.end = last.end.max(iv.end),
+                _ => merged.push(iv),
+            }
+        }
+        IntervalSet{ intervals: merged }
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // The number of integers in the set.
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::zero(), |sum, iv| sum + iv.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|iv| iv.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|iv| iv.end - T::one())
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.intervals.partition_point(|iv| iv.end <= x);
+        i < self.intervals.len() && self.intervals[i].contains(x)
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = IntervalSet::normalized(intervals);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ret = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            ret.push(a.intersect(&b));
+            if a.end < b.end { i += 1; } else { j += 1; }
+        }
+        IntervalSet::normalized(ret)
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ret = Vec::new();
+        let mut j = 0;
+        for a in &self.intervals {
+            let mut pos = a.start;
+            while j < other.intervals.len() && other.intervals[j].end <= a.start {
+                j += 1;
+            }
+            for b in other.intervals[j..].iter().take_while(|b| b.start < a.end) {
+                ret.push(Interval::new(pos, b.start.min(a.end)));
+                pos = pos.max(b.end);
+            }
+            ret.push(Interval::new(pos, a.end));
+        }
+        IntervalSet::normalized(ret)
+    }
+
+    // The members below x and the members from x on.
+    pub fn split_at(&self, x: T) -> (IntervalSet<T>, IntervalSet<T>) {
+        let (below, above): (Vec<_>, Vec<_>) = self.iter().map(|iv| iv.split_at(x)).unzip();
+        (IntervalSet::normalized(below), IntervalSet::normalized(above))
+    }
+
+    pub fn shift(&self, offset: T) -> IntervalSet<T> {
+        IntervalSet{ intervals: self.iter().map(|iv| iv.shift(offset)).collect() }
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> IntervalSet<T> {
+        IntervalSet::normalized(vec![interval])
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> IntervalSet<T> {
+        IntervalSet::normalized(iter.into_iter().collect())
+    }
+}
+
+// A function that adds a different offset to the values in each of a number of disjoint
+// intervals, and leaves all other values as they are.
+#[derive(Debug, Clone, Default)]
+pub struct OffsetMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: PrimInt> OffsetMap<T> {
+    pub fn new() -> OffsetMap<T> {
+        OffsetMap{ pieces: Vec::new() }
+    }
+
+    // Values in the interval get the offset added. The interval must not overlap any earlier one.
+    pub fn insert(&mut self, interval: Interval<T>, offset: T) {
+        self.pieces.push((interval, offset));
+    }
+
+    pub fn map(&self, x: T) -> T {
+        self.pieces.iter()
+            .find(|(iv, _)| iv.contains(x))
+            .map_or(x, |&(_, offset)| x + offset)
+    }
+
+    // The image of every member of the set.
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut unmapped = set.clone();
+        let mut ret = Vec::new();
+        for &(iv, offset) in &self.pieces {
+            let inside = set.intersection(&iv.into());
+            ret.extend(inside.iter().map(|part| part.shift(offset)));
+            unmapped = unmapped.difference(&inside);
+        }
+        ret.extend(unmapped.intervals);
+        IntervalSet::normalized(ret)
+    }
+}
+
+// A box in N dimensions, holding the points whose every coordinate is in the interval for its axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoxN<const N: usize> {
+    pub axes: [Interval<i64>; N],
+}
+
+impl<const N: usize> BoxN<N> {
+    pub fn new(axes: [Interval<i64>; N]) -> BoxN<N> {
+        BoxN{ axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|iv| iv.is_empty())
+    }
+
+    // The number of points in the box.
+    pub fn volume(&self) -> i64 {
+        self.axes.iter().map(|iv| iv.len()).product()
+    }
+
+    pub fn intersect(&self, other: &BoxN<N>) -> BoxN<N> {
+        let mut axes = self.axes;
+        for (iv, o) in axes.iter_mut().zip(other.axes.iter()) {
+            *iv = iv.intersect(o);
+        }
+        BoxN{ axes }
+    }
+
+    pub fn intersects(&self, other: &BoxN<N>) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    pub fn with_axis(&self, axis: usize, interval: Interval<i64>) -> BoxN<N> {
+        let mut axes = self.axes;
+        axes[axis] = interval;
+        BoxN{ axes }
+    }
+
+    pub fn shift(&self, axis: usize, offset: i64) -> BoxN<N> {
+        self.with_axis(axis, self.axes[axis].shift(offset))
+    }
+
+    // The parts with the coordinate on the axis below x and from x on.
+    pub fn split_at(&self, axis: usize, x: i64) -> (BoxN<N>, BoxN<N>) {
+        let (below, above) = self.axes[axis].split_at(x);
+        (self.with_axis(axis, below), self.with_axis(axis, above))
+    }
+
+    // The parts where the coordinate on the axis does and doesn't satisfy the predicate. The
+    // predicate must be a threshold, changing value at most once along the axis.
+    pub fn split_by<F: Fn(i64) -> bool>(&self, axis: usize, pred: F) -> (BoxN<N>, BoxN<N>) {
+        let iv = self.axes[axis];
+        if iv.is_empty() {
+            return (*self, *self);
+        }
+        let first = pred(iv.start);
+        let (mut lo, mut hi) = (iv.start, iv.end);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(mid) == first { lo = mid + 1; } else { hi = mid; }
+        }
+        let (below, above) = self.split_at(axis, lo);
+        if first { (below, above) } else { (above, below) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals.iter().map(|&(s, e)| Interval::new(s, e)).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let iv = Interval::new(3, 8);
+        assert_eq!(iv.len(), 5);
+        assert!(iv.contains(3) && !iv.contains(8));
+        assert_eq!(Interval::inclusive(1, 4000).len(), 4000);
+        assert_eq!(iv.intersect(&Interval::new(6, 10)), Interval::new(6, 8));
+        assert!(iv.intersect(&Interval::new(8, 10)).is_empty());
+        assert_eq!(iv.split_at(5), (Interval::new(3, 5), Interval::new(5, 8)));
+        assert_eq!(iv.split_at(0).1, iv);
+        assert!(iv.split_at(10).1.is_empty());
+        assert_eq!(iv.shift(-3), Interval::new(0, 5));
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let a = set(&[(5, 8), (0, 2), (1, 3), (3, 4), (9, 9)]);
+        assert_eq!(a.intervals(), &[Interval::new(0, 4), Interval::new(5, 8)]);
+        assert_eq!(a.len(), 7);
+        assert_eq!((a.min(), a.max()), (Some(0), Some(7)));
+        assert!(a.contains(3) && !a.contains(4) && a.contains(5) && !a.contains(8));
+
+        let b = set(&[(2, 6), (7, 10)]);
+        assert_eq!(a.union(&b), set(&[(0, 10)]));
+        assert_eq!(a.intersection(&b), set(&[(2, 4), (5, 6), (7, 8)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (6, 7)]));
+        assert_eq!(b.difference(&a), set(&[(4, 5), (8, 10)]));
+        assert_eq!(a.split_at(6), (set(&[(0, 4), (5, 6)]), set(&[(6, 8)])));
+        assert_eq!(a.shift(10), set(&[(10, 14), (15, 18)]));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_box() {
+        let b = BoxN::new([Interval::inclusive(1, 4), Interval::inclusive(1, 3)]);
+        assert_eq!(b.volume(), 12);
+        assert!(!b.is_empty());
+
+        let (small, large) = b.split_by(0, |x| x < 3);
+        assert_eq!((small.volume(), large.volume()), (6, 6));
+        assert_eq!(small.axes[0], Interval::new(1, 3));
+        let (large, small) = b.split_by(1, |y| y > 1);
+        assert_eq!((small.volume(), large.volume()), (4, 8));
+        let (all, none) = b.split_by(0, |x| x < 10);
+        assert_eq!(all, b);
+        assert!(none.is_empty());
+
+        let other = b.shift(0, 3);
+        assert_eq!(b.intersect(&other).volume(), 3);
+        assert!(b.intersects(&other));
+        assert!(!b.intersects(&b.shift(1, 3)));
+    }
+
+    #[test]
+    fn test_offset_map() {
+        let mut m = OffsetMap::new();
+        m.insert(Interval::new(11, 13), 100);
+        m.insert(Interval::new(15, 17), 100);
+        assert_eq!((m.map(10), m.map(12), m.map(13)), (10, 112, 13));
+        assert_eq!(m.map_set(&set(&[(10, 20)])),
+                   set(&[(10, 11), (13, 15), (17, 20), (111, 113), (115, 117)]));
+        assert_eq!(m.map_set(&set(&[(0, 5)])), set(&[(0, 5)]));
+    }
+}
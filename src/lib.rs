@@ -1,4 +1,18 @@
+pub mod error;
 pub mod grid;
+pub mod point;
+pub mod geometry;
+pub mod graph;
+pub mod interval;
 pub mod utils;
+pub mod input;
 pub mod dir;
 pub mod numeric;
+pub mod search;
+pub mod cycle;
+pub mod solver;
+pub mod days;
+pub mod verify;
+pub mod timing;
+
+pub use error::{Error, Result};
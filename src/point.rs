@@ -0,0 +1,176 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// A position on a grid, or the difference between two. Points are ordered in reading order,
+// by row and then by column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub fn new(row: i32, col: i32) -> Point {
+        Point{ row, col }
+    }
+
+    // The number of orthogonal steps from the origin.
+    pub fn manhattan(self) -> i32 {
+        self.row.abs() + self.col.abs()
+    }
+
+    // The number of steps from the origin when diagonal steps are allowed.
+    pub fn chebyshev(self) -> i32 {
+        self.row.abs().max(self.col.abs())
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((row, col): (i32, i32)) -> Point {
+        Point{ row, col }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point {
+        Point{ row: self.row + other.row, col: self.col + other.col }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point {
+        Point{ row: self.row - other.row, col: self.col - other.col }
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+    fn mul(self, k: i32) -> Point {
+        Point{ row: self.row * k, col: self.col * k }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+    fn neg(self) -> Point {
+        Point{ row: -self.row, col: -self.col }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+// A position in space, with room for coordinates too large for a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3{ x, y, z }
+    }
+
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    pub fn chebyshev(self) -> i64 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Point3 {
+        Point3{ x, y, z }
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+    fn add(self, other: Point3) -> Point3 {
+        Point3{ x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+    fn sub(self, other: Point3) -> Point3 {
+        Point3{ x: self.x - other.x, y: self.y - other.y, z: self.z - other.z }
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Point3;
+    fn mul(self, k: i64) -> Point3 {
+        Point3{ x: self.x * k, y: self.y * k, z: self.z * k }
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+    fn neg(self) -> Point3 {
+        Point3{ x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, other: Point3) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, other: Point3) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let p = Point::new(2, -3);
+        let q = Point::from((1, 1));
+        assert_eq!(p + q, Point::new(3, -2));
+        assert_eq!(p - q, Point::new(1, -4));
+        assert_eq!(p * 2, Point::new(4, -6));
+        assert_eq!(-p, Point::new(-2, 3));
+        assert_eq!(p.manhattan(), 5);
+        assert_eq!(p.chebyshev(), 3);
+
+        let mut r = p;
+        r += q;
+        r -= q * 2;
+        assert_eq!(r, p - q);
+
+        let mut points = vec![Point::new(1, 0), Point::new(0, 5), Point::new(0, 2)];
+        points.sort();
+        assert_eq!(points, vec![Point::new(0, 2), Point::new(0, 5), Point::new(1, 0)]);
+    }
+
+    #[test]
+    fn test_point3() {
+        let p = Point3::new(1, -2, 3);
+        assert_eq!(p + Point3::from((1, 1, 1)), Point3::new(2, -1, 4));
+        assert_eq!(p - p, Point3::default());
+        assert_eq!(-p * 2, Point3::new(-2, 4, -6));
+        assert_eq!(p.manhattan(), 6);
+        assert_eq!(p.chebyshev(), 3);
+        assert!(Point3::new(0, 9, 9) < Point3::new(1, 0, 0));
+    }
+}
@@ -0,0 +1,207 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+// Shortest path searches over any state type. Each search starts from a single state, expands
+// states with a successor closure and stops at the first state accepted by the goal predicate.
+// The plain versions return the cost of reaching the goal; the _path versions also return the
+// states along the way, from the start to the goal inclusive. The explore_ versions return
+// everything the search found, so a goal that never matches gives distances to every reachable
+// state.
+
+pub struct Explored<S> {
+    dist: HashMap<S, i64>,
+    prev: Option<HashMap<S, S>>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Explored<S> {
+    fn new(start: S, keep_path: bool) -> Explored<S> {
+        let mut dist = HashMap::new();
+        dist.insert(start, 0);
+        Explored{ dist, prev: if keep_path { Some(HashMap::new()) } else { None }, goal: None }
+    }
+
+    fn reach(&mut self, state: S, dist: i64, from: &S) {
+        if let Some(prev) = self.prev.as_mut() {
+            prev.insert(state.clone(), from.clone());
+        }
+        self.dist.insert(state, dist);
+    }
+
+    // The distances to every state reached. States that were queued but not yet expanded when
+    // the goal was found have tentative distances that may not be the shortest.
+    pub fn distances(&self) -> &HashMap<S, i64> { &self.dist }
+    pub fn distance(&self, state: &S) -> Option<i64> { self.dist.get(state).copied() }
+
+    pub fn goal(&self) -> Option<&S> { self.goal.as_ref() }
+    pub fn cost(&self) -> Option<i64> { self.goal.as_ref().and_then(|g| self.distance(g)) }
+
+    // The states leading from the start to the given one, or None if it wasn't reached or the
+    // search didn't keep paths.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let prev = self.prev.as_ref()?;
+        if !self.dist.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(p) = prev.get(path.last().unwrap()) {
+            path.push(p.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn path(&self) -> Option<Vec<S>> { self.path_to(self.goal.as_ref()?) }
+
+    fn found(&self) -> Option<(i64, Vec<S>)> {
+        Some((self.cost()?, self.path().unwrap_or_default()))
+    }
+}
+
+pub fn explore_bfs<S, I, F, G>(start: S, mut successors: F, mut goal: G, keep_path: bool) -> Explored<S>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = S>, F: FnMut(&S) -> I, G: FnMut(&S) -> bool
+{
+    let mut explored = Explored::new(start.clone(), keep_path);
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(state) = queue.pop_front() {
+        let d = explored.dist[&state];
+        if goal(&state) {
+            explored.goal = Some(state);
+            break;
+        }
+        for next in successors(&state) {
+            if !explored.dist.contains_key(&next) {
+                explored.reach(next.clone(), d + 1, &state);
+                queue.push_back(next);
+            }
+        }
+    }
+    explored
+}
+
+// Dijkstra's algorithm, or A* with a non-zero heuristic. The heuristic must never overestimate
+// the remaining cost, or the result may not be the cheapest.
+fn best_first<S, I, F, H, G>(start: S, mut successors: F, heuristic: H, mut goal: G, keep_path: bool) -> Explored<S>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, i64)>, F: FnMut(&S) -> I,
+          H: Fn(&S) -> i64, G: FnMut(&S) -> bool
+{
+    let mut explored = Explored::new(start.clone(), keep_path);
+    let mut pq = PriorityQueue::new();
+    pq.push(start.clone(), Reverse(heuristic(&start)));
+
+    while let Some((state, _)) = pq.pop() {
+        let d = explored.dist[&state];
+        if goal(&state) {
+            explored.goal = Some(state);
+            break;
+        }
+        for (next, cost) in successors(&state) {
+            let next_dist = d + cost;
+            if explored.distance(&next).is_none_or(|old| next_dist < old) {
+                explored.reach(next.clone(), next_dist, &state);
+                let prio = Reverse(next_dist + heuristic(&next));
+                pq.push_increase(next, prio);
+            }
+        }
+    }
+    explored
+}
+
+pub fn explore_dijkstra<S, I, F, G>(start: S, successors: F, goal: G, keep_path: bool) -> Explored<S>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, i64)>, F: FnMut(&S) -> I, G: FnMut(&S) -> bool
+{
+    best_first(start, successors, |_| 0, goal, keep_path)
+}
+
+// Breadth first search, where every step costs one.
+pub fn bfs<S, I, F, G>(start: S, successors: F, goal: G) -> Option<i64>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = S>, F: FnMut(&S) -> I, G: FnMut(&S) -> bool
+{
+    explore_bfs(start, successors, goal, false).cost()
+}
+
+pub fn bfs_path<S, I, F, G>(start: S, successors: F, goal: G) -> Option<(i64, Vec<S>)>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = S>, F: FnMut(&S) -> I, G: FnMut(&S) -> bool
+{
+    explore_bfs(start, successors, goal, true).found()
+}
+
+// The successors come with the cost of moving to them, which must not be negative.
+pub fn dijkstra<S, I, F, G>(start: S, successors: F, goal: G) -> Option<i64>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, i64)>, F: FnMut(&S) -> I, G: FnMut(&S) -> bool
+{
+    explore_dijkstra(start, successors, goal, false).cost()
+}
+
+pub fn dijkstra_path<S, I, F, G>(start: S, successors: F, goal: G) -> Option<(i64, Vec<S>)>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, i64)>, F: FnMut(&S) -> I, G: FnMut(&S) -> bool
+{
+    explore_dijkstra(start, successors, goal, true).found()
+}
+
+// Like dijkstra, guided by a heuristic that must never overestimate the cost to the goal.
+pub fn astar<S, I, F, H, G>(start: S, successors: F, heuristic: H, goal: G) -> Option<i64>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, i64)>, F: FnMut(&S) -> I,
+          H: Fn(&S) -> i64, G: FnMut(&S) -> bool
+{
+    best_first(start, successors, heuristic, goal, false).cost()
+}
+
+pub fn astar_path<S, I, F, H, G>(start: S, successors: F, heuristic: H, goal: G) -> Option<(i64, Vec<S>)>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item = (S, i64)>, F: FnMut(&S) -> I,
+          H: Fn(&S) -> i64, G: FnMut(&S) -> bool
+{
+    best_first(start, successors, heuristic, goal, true).found()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A line of numbers where you can step one forward for 1, or jump three forward for 2.
+    fn moves(&n: &i64) -> Vec<(i64, i64)> {
+        vec![(n + 1, 1), (n + 3, 2)]
+    }
+
+    #[test]
+    fn test_bfs() {
+        let succ = |&n: &i64| vec![n + 1, n * 2];
+        assert_eq!(bfs(1, succ, |&n| n == 10), Some(4));
+        assert_eq!(bfs_path(1, succ, |&n| n == 10), Some((4, vec![1, 2, 4, 5, 10])));
+        assert_eq!(bfs(0, |&n: &i64| if n < 5 { vec![n + 1] } else { vec![] }, |&n| n == 10), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(dijkstra(0, moves, |&n| n == 7), Some(5));
+        assert_eq!(dijkstra_path(0, moves, |&n| n == 6), Some((4, vec![0, 3, 6])));
+        assert_eq!(dijkstra(0, |&n: &i64| if n < 5 { moves(&n) } else { vec![] }, |&n| n == 10), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let h = |&n: &i64| (10 - n).max(0) / 3;
+        assert_eq!(astar(0, moves, h, |&n| n == 10), Some(7));
+        assert_eq!(astar_path(0, moves, h, |&n| n == 10).map(|(_, path)| path.len()), Some(5));
+    }
+
+    #[test]
+    fn test_explore() {
+        let bounded = |&n: &i64| if n < 6 { moves(&n) } else { vec![] };
+        let explored = explore_dijkstra(0, bounded, |_| false, true);
+        assert_eq!(explored.goal(), None);
+        assert_eq!(explored.cost(), None);
+        assert_eq!(explored.distances().len(), 9);
+        assert_eq!(explored.distance(&6), Some(4));
+        assert_eq!(explored.path_to(&6), Some(vec![0, 3, 6]));
+        assert_eq!(explored.path_to(&20), None);
+
+        let explored = explore_bfs(1, |&n: &i64| vec![n + 1, n * 2], |&n| n == 10, false);
+        assert_eq!(explored.cost(), Some(4));
+        assert_eq!(explored.path(), None);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::error::Result;
+use num_bigint::BigInt;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    BigInt(BigInt),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::BigInt(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Answer { Answer::Int(n) }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer { Answer::Int(n as i64) }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Answer { Answer::BigInt(n) }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer { Answer::Str(s) }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer { Answer::Str(String::from(s)) }
+}
+
+pub trait Solver {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+pub type SolveFn = fn(&str, &[Part]) -> Result<Vec<Answer>>;
+
+// Parse the input once and solve the requested parts, in the order given.
+pub fn solve<S: Solver>(input: &str, parts: &[Part]) -> Result<Vec<Answer>> {
+    let parsed = S::parse(input)?;
+    Ok(parts.iter()
+        .map(|part| match part {
+            Part::One => S::part1(&parsed),
+            Part::Two => S::part2(&parsed),
+        })
+        .collect())
+}
+
+// How long each phase took, with None for a part that wasn't solved.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+pub type TimeFn = fn(&str, &[Part]) -> Result<Timings>;
+
+// Solve the requested parts, measuring how long each phase takes.
+pub fn time<S: Solver>(input: &str, parts: &[Part]) -> Result<Timings> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let mut timings = Timings{ parse, part1: None, part2: None };
+    for part in parts {
+        let start = Instant::now();
+        match part {
+            Part::One => {
+                black_box(S::part1(black_box(&parsed)));
+                timings.part1 = Some(start.elapsed());
+            },
+            Part::Two => {
+                black_box(S::part2(black_box(&parsed)));
+                timings.part2 = Some(start.elapsed());
+            },
+        }
+    }
+    Ok(timings)
+}
+
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub solve: SolveFn,
+    pub time: TimeFn,
+}
+
+impl Puzzle {
+    pub fn of<S: Solver>() -> Puzzle {
+        Puzzle{ solve: solve::<S>, time: time::<S> }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42i64).to_string(), "42");
+        assert_eq!(Answer::from(7usize), Answer::Int(7));
+        assert_eq!(Answer::from(BigInt::from(-3)).to_string(), "-3");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
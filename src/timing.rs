@@ -0,0 +1,126 @@
+use crate::solver::Timings;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+pub fn stats(samples: &[Duration]) -> Stats {
+    assert!(!samples.is_empty());
+    let mut sorted = Vec::from(samples);
+    sorted.sort();
+    let n = sorted.len();
+    let median = if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+    };
+    Stats{ min: sorted[0], median, max: sorted[n - 1] }
+}
+
+// Timing statistics for one day, over a number of repeated runs. A part that wasn't solved has
+// no statistics.
+pub struct DayTiming {
+    pub day: u32,
+    pub reps: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayTiming {
+    pub fn new(day: u32, runs: &[Timings]) -> DayTiming {
+        let part = |f: fn(&Timings) -> Option<Duration>| {
+            let samples: Vec<Duration> = runs.iter().filter_map(f).collect();
+            if samples.is_empty() { None } else { Some(stats(&samples)) }
+        };
+        DayTiming {
+            day,
+            reps: runs.len(),
+            parse: stats(&runs.iter().map(|t| t.parse).collect::<Vec<_>>()),
+            part1: part(|t| t.part1),
+            part2: part(|t| t.part2),
+        }
+    }
+
+    fn phases(&self) -> Vec<(&'static str, &Stats)> {
+        let parts = [("part1", &self.part1), ("part2", &self.part2)];
+        let mut ret = vec![("parse", &self.parse)];
+        ret.extend(parts.iter().filter_map(|&(phase, s)| s.as_ref().map(|s| (phase, s))));
+        ret
+    }
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+pub fn to_table(rows: &[DayTiming]) -> String {
+    let mut ret = format!("{:<5} {:<6} {:>12} {:>12} {:>12}\n", "day", "phase", "min ms", "median ms", "max ms");
+    for row in rows {
+        for (phase, s) in row.phases() {
+            ret += &format!("{:<5} {:<6} {:>12.3} {:>12.3} {:>12.3}\n",
+                            format!("{:02}", row.day), phase, millis(s.min), millis(s.median), millis(s.max));
+        }
+    }
+    ret
+}
+
+pub fn to_csv(rows: &[DayTiming]) -> String {
+    let mut ret = String::from("day,phase,reps,min_us,median_us,max_us\n");
+    for row in rows {
+        for (phase, s) in row.phases() {
+            ret += &format!("{},{},{},{},{},{}\n",
+                            row.day, phase, row.reps, s.min.as_micros(), s.median.as_micros(), s.max.as_micros());
+        }
+    }
+    ret
+}
+
+pub fn to_json(rows: &[DayTiming]) -> String {
+    let entries: Vec<String> = rows.iter()
+        .flat_map(|row| row.phases().iter().map(|(phase, s)| {
+            format!("  {{\"day\": {}, \"phase\": \"{}\", \"reps\": {}, \"min_us\": {}, \"median_us\": {}, \"max_us\": {}}}",
+                    row.day, phase, row.reps, s.min.as_micros(), s.median.as_micros(), s.max.as_micros())
+        }).collect::<Vec<_>>())
+        .collect();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration { Duration::from_millis(n) }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(stats(&[ms(5)]), Stats{ min: ms(5), median: ms(5), max: ms(5) });
+        assert_eq!(stats(&[ms(9), ms(1), ms(4)]), Stats{ min: ms(1), median: ms(4), max: ms(9) });
+        assert_eq!(stats(&[ms(9), ms(1), ms(4), ms(2)]), Stats{ min: ms(1), median: ms(3), max: ms(9) });
+    }
+
+    #[test]
+    fn test_formats() {
+        let runs = [
+            Timings{ parse: ms(1), part1: Some(ms(2)), part2: Some(ms(30)) },
+            Timings{ parse: ms(3), part1: Some(ms(2)), part2: Some(ms(10)) },
+        ];
+        let rows = [DayTiming::new(7, &runs)];
+        assert_eq!(to_csv(&rows), "day,phase,reps,min_us,median_us,max_us\n\
+                                   7,parse,2,1000,2000,3000\n\
+                                   7,part1,2,2000,2000,2000\n\
+                                   7,part2,2,10000,20000,30000\n");
+        assert!(to_json(&rows).contains("{\"day\": 7, \"phase\": \"part2\", \"reps\": 2, \"min_us\": 10000, \"median_us\": 20000, \"max_us\": 30000}"));
+        assert!(to_table(&rows).contains("07    part2        10.000       20.000       30.000"));
+
+        // Parts that weren't solved are left out.
+        let runs = [Timings{ parse: ms(1), part1: None, part2: Some(ms(4)) }];
+        assert_eq!(to_csv(&[DayTiming::new(8, &runs)]), "day,phase,reps,min_us,median_us,max_us\n\
+                                                       8,parse,1,1000,1000,1000\n\
+                                                       8,part2,1,4000,4000,4000\n");
+    }
+}
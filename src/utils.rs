@@ -1,18 +1,50 @@
-use regex::Regex;
-use std::io;
+use crate::error::{Error, Result};
+use regex::{Captures, Regex};
+use std::fmt::Display;
+use std::str::FromStr;
 
-pub fn grab_numbers(line: &str) -> Vec<i64> {
+pub fn grab_numbers(line: &str) -> Result<Vec<i64>> {
     let pattern = Regex::new("(-?\\d+)").unwrap();
     pattern
-        .captures_iter(line)
-        .map(|c| c.get(1).unwrap())
-        .map(|s| s.as_str().parse::<i64>().unwrap())
+        .find_iter(line)
+        .map(|m| parse_num(m.as_str()).map_err(|e| e.at_col(m.start() + 1)))
         .collect()
 }
 
-pub fn stdio_lines() -> Vec<String> {
-    io::stdin()
-        .lines()
-        .map(|l| l.unwrap())
+pub fn parse_num<T: FromStr>(s: &str) -> Result<T> where T::Err: Display {
+    s.parse().map_err(|e| Error::new(format!("Bad number {:?}: {}", s, e)))
+}
+
+pub fn captures<'a>(pattern: &Regex, line: &'a str) -> Result<Captures<'a>> {
+    pattern.captures(line).ok_or_else(|| Error::new(format!("Expected a line matching {}", pattern)))
+}
+
+// Parse each line of the input, tagging any error with the line it came from.
+pub fn parse_lines<T, F>(input: &str, mut f: F) -> Result<Vec<T>>
+    where F: FnMut(&str) -> Result<T>
+{
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1)))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grab_numbers() {
+        assert_eq!(grab_numbers("x=12, y=-3 z"), Ok(vec![12, -3]));
+        assert_eq!(grab_numbers("no numbers"), Ok(vec![]));
+        let err = grab_numbers("1 99999999999999999999").unwrap_err();
+        assert_eq!(err.col(), Some(3));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n2\n", parse_num::<i64>), Ok(vec![1, 2]));
+        let err = parse_lines("1\nx\n", parse_num::<i64>).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: Bad number \"x\": invalid digit found in string");
+    }
+}
@@ -0,0 +1,175 @@
+use crate::solver::{solve, Answer, Part, Solver};
+use std::fmt;
+use toml::{Table, Value};
+
+// Expected answers for one day and input, as listed in the answers file:
+//
+//   [day01.example1]
+//   part1 = 142
+//   part2 = "281"
+//
+// Either part may be left out, e.g. when an example only covers one of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+    pub day: u32,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn parts(&self) -> Vec<(Part, &str)> {
+        let mut ret = Vec::new();
+        if let Some(answer) = &self.part1 {
+            ret.push((Part::One, answer.as_str()));
+        }
+        if let Some(answer) = &self.part2 {
+            ret.push((Part::Two, answer.as_str()));
+        }
+        ret
+    }
+}
+
+fn parse_day(key: &str) -> Option<u32> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+fn parse_answer(value: &Value) -> Option<String> {
+    match value {
+        Value::Integer(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+fn parse_entry(day: u32, input: &str, value: &Value) -> Result<Expected, String> {
+    let context = format!("day{:02}.{}", day, input);
+    let table = value.as_table().ok_or(format!("{}: expected a table of answers", context))?;
+    let mut expected = Expected{ day, input: String::from(input), part1: None, part2: None };
+    for (key, value) in table {
+        let answer = parse_answer(value).ok_or(format!("{}.{}: answer must be an integer or a string", context, key))?;
+        match key.as_str() {
+            "part1" => expected.part1 = Some(answer),
+            "part2" => expected.part2 = Some(answer),
+            _ => return Err(format!("{}: unknown key {}", context, key)),
+        }
+    }
+    Ok(expected)
+}
+
+pub fn parse_answers(text: &str) -> Result<Vec<Expected>, String> {
+    let table: Table = text.parse().map_err(|e: toml::de::Error| e.to_string())?;
+    let mut ret = Vec::new();
+    for (day_key, inputs) in &table {
+        let day = parse_day(day_key).ok_or(format!("{}: expected a key like day01", day_key))?;
+        let inputs = inputs.as_table().ok_or(format!("{}: expected a table of inputs", day_key))?;
+        for (input, value) in inputs {
+            ret.push(parse_entry(day, input, value)?);
+        }
+    }
+    ret.sort_by(|e1, e2| (e1.day, &e1.input).cmp(&(e2.day, &e2.input)));
+    Ok(ret)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+}
+
+pub fn check(expected: &str, actual: &Answer) -> Outcome {
+    let actual = actual.to_string();
+    if actual == expected {
+        Outcome::Pass
+    } else {
+        Outcome::Fail { expected: String::from(expected), actual }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "ok"),
+            Outcome::Fail { expected, actual } => write!(f, "FAIL (expected {}, got {})", expected, actual),
+        }
+    }
+}
+
+// The answers file, built in so that tests don't depend on the working directory.
+pub const ANSWERS: &str = include_str!("../answers.toml");
+
+// Solve one part of each example and check it against the answers file. Every example must have
+// an answer for the part. Panics on the first wrong answer, for use in tests.
+pub fn check_examples<S: Solver>(day: u32, part: Part, examples: &[(&str, &str)]) {
+    let entries = parse_answers(ANSWERS).unwrap();
+    for (name, input) in examples {
+        let expected = entries.iter()
+            .find(|e| e.day == day && e.input == *name)
+            .and_then(|e| e.parts().into_iter().find(|(p, _)| *p == part).map(|(_, answer)| String::from(answer)))
+            .unwrap_or_else(|| panic!("No answer for day{:02}.{} {}", day, name, part));
+        let answers = solve::<S>(input, &[part])
+            .unwrap_or_else(|e| panic!("day{:02}.{}: {}", day, name, e));
+        let outcome = check(&expected, &answers[0]);
+        assert_eq!(outcome, Outcome::Pass, "day{:02}.{} {}", day, name, part);
+    }
+}
+
+// Generate a test for each part that solves the listed examples from inputs/dayNN/, which are
+// built into the test binary, and checks them against the answers file:
+//
+//   example_tests!(super::Day01, "01", part1: ["example1"], part2: ["example2"]);
+#[macro_export]
+macro_rules! example_tests {
+    ($solver:ty, $day:literal, $($part:ident: [$($name:literal),+]),+ $(,)?) => {
+        $(
+            $crate::example_tests!(@part $solver, $day, $part, [$($name),+]);
+        )+
+    };
+    // Only part1 and part2 are accepted, so a misspelled part fails to compile.
+    (@part $solver:ty, $day:literal, part1, [$($name:literal),+]) => {
+        $crate::example_tests!(@test part1, $crate::solver::Part::One, $solver, $day, [$($name),+]);
+    };
+    (@part $solver:ty, $day:literal, part2, [$($name:literal),+]) => {
+        $crate::example_tests!(@test part2, $crate::solver::Part::Two, $solver, $day, [$($name),+]);
+    };
+    (@test $test:ident, $part:expr, $solver:ty, $day:literal, [$($name:literal),+]) => {
+        #[test]
+        fn $test() {
+            let examples = [$(
+                ($name, include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/day", $day, "/", $name, ".txt")))
+            ),+];
+            $crate::verify::check_examples::<$solver>($day.parse().unwrap(), $part, &examples);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let text = "
+[day10.example2]
+part2 = 8
+
+[day01.example1]
+part1 = 142
+part2 = \"281\"
+";
+        assert_eq!(parse_answers(text), Ok(vec![
+            Expected{ day: 1, input: String::from("example1"), part1: Some(String::from("142")), part2: Some(String::from("281")) },
+            Expected{ day: 10, input: String::from("example2"), part1: None, part2: Some(String::from("8")) },
+        ]));
+        assert!(parse_answers("[dayone.example]\npart1 = 1").is_err());
+        assert!(parse_answers("[day01.example]\npart3 = 1").is_err());
+        assert!(parse_answers("[day01.example]\npart1 = 1.5").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check("142", &Answer::Int(142)), Outcome::Pass);
+        assert_eq!(check("142", &Answer::Int(143)),
+                   Outcome::Fail{ expected: String::from("142"), actual: String::from("143") });
+    }
+}